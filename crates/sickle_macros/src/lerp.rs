@@ -0,0 +1,83 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{quote, quote_spanned};
+use syn::{AttrStyle, Data, Field, Fields, Index, Member};
+
+pub(crate) fn derive_lerp_macro(ast: &syn::DeriveInput) -> TokenStream {
+    let name_ident = &ast.ident;
+    let Data::Struct(struct_data) = &ast.data else {
+        return quote_spanned! {
+            name_ident.span() => compile_error!("Unsupported Data type, only Structs are supported");
+        }
+        .into();
+    };
+
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let body = match &struct_data.fields {
+        Fields::Named(fields) => {
+            let field_values: Vec<proc_macro2::TokenStream> = fields
+                .named
+                .iter()
+                .map(|field| {
+                    // Safe unwrap: named fields always have an ident
+                    let ident = field.ident.clone().unwrap();
+                    let value = to_field_lerp(field, Member::Named(ident.clone()));
+                    quote! { #ident: #value, }
+                })
+                .collect();
+
+            quote! {
+                Self {
+                    #(#field_values)*
+                }
+            }
+        }
+        Fields::Unnamed(fields) => {
+            let field_values: Vec<proc_macro2::TokenStream> = fields
+                .unnamed
+                .iter()
+                .enumerate()
+                .map(|(i, field)| {
+                    let index = Index {
+                        index: i as u32,
+                        span: Span::call_site(),
+                    };
+                    let value = to_field_lerp(field, Member::Unnamed(index));
+                    quote! { #value, }
+                })
+                .collect();
+
+            quote! {
+                Self(#(#field_values)*)
+            }
+        }
+        Fields::Unit => quote! { Self },
+    };
+
+    quote! {
+        impl #impl_generics ::sickle_math::lerp::Lerp for #name_ident #ty_generics #where_clause {
+            fn lerp(&self, to: Self, t: f32) -> Self {
+                #body
+            }
+        }
+    }
+    .into()
+}
+
+fn to_field_lerp(field: &Field, member: Member) -> proc_macro2::TokenStream {
+    let snap = field
+        .attrs
+        .iter()
+        .any(|attr| attr.style == AttrStyle::Outer && attr.path().is_ident("snap"));
+
+    if snap {
+        quote! {
+            if t < 1. { self.#member.clone() } else { to.#member }
+        }
+    } else {
+        quote! {
+            ::sickle_math::lerp::Lerp::lerp(&self.#member, to.#member, t)
+        }
+    }
+}
@@ -1,6 +1,8 @@
 mod default_theme;
 mod event_handler;
+mod lerp;
 mod simple_interaction_plugin;
+mod style_attributes;
 mod style_command;
 mod style_commands;
 mod ui_context;
@@ -131,6 +133,73 @@ pub fn style_commands_macro_derive(input: TokenStream) -> TokenStream {
     style_commands::derive_style_commands_macro(&ast)
 }
 
+/// Derives `sickle_math::lerp::Lerp` for structs and tuple structs.
+///
+/// Every field must implement `Lerp`, unless it is marked with `#[snap]`. Snapped fields
+/// must implement `Clone`; they keep their starting value until `t` reaches `1.`.
+/// The generated impl refers to `::sickle_math::lerp::Lerp`, so the deriving crate must
+/// depend on `sickle_math`.
+///
+/// # Usage
+///
+/// ```
+/// #[derive(Clone, Default, PartialEq, Lerp)]
+/// struct Progress {
+///     value: f32,
+///     color: Color,
+///     #[snap]
+///     label: String,
+/// }
+/// ```
+#[proc_macro_derive(Lerp, attributes(snap))]
+pub fn lerp_macro_derive(input: TokenStream) -> TokenStream {
+    let ast: DeriveInput = syn::parse(input.clone()).unwrap();
+    lerp::derive_lerp_macro(&ast)
+}
+
+/// Generates style commands and `StyleBuilder` extensions for the fields of a component,
+/// so it can be themed like the built-in style attributes.
+///
+/// For each field `value` of component `Progress` this generates:
+/// - a `SetProgressValue` entity command and `progress_value` on `UiStyle` / `UiStyleUnchecked`
+/// - `progress_value` on `StyleBuilder`, `InteractiveStyleBuilder`
+///   and, for `#[animatable]` fields, `AnimatedStyleBuilder`
+/// - a `Progress::LOCKABLE_VALUE` constant to use with `LockedStyleAttributes`
+///
+/// Fields must implement `Clone + PartialEq + Send + Sync`. Animatable fields must also implement
+/// `Lerp + Default`. Use `#[static_style_only]` to skip the interactive builder, and `#[skip_style]`
+/// to skip a field entirely.
+///
+/// See the `custom_style_attributes` example for a complete themed component.
+///
+/// # Usage
+///
+/// ```
+/// #[derive(Component, StyleAttributes)]
+/// pub struct Progress {
+///     #[animatable]
+///     value: f32,
+///     #[animatable]
+///     color: Color,
+/// }
+///
+/// fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+///     style_builder
+///         .animated()
+///         .progress_value(AnimatedVals {
+///             idle: 0.,
+///             hover: Some(1.),
+///             ..default()
+///         })
+///         .copy_from(theme_data.interaction_animation);
+/// }
+/// ```
+#[proc_macro_derive(StyleAttributes, attributes(animatable, static_style_only, skip_style))]
+pub fn style_attributes_macro_derive(input: TokenStream) -> TokenStream {
+    let ast: DeriveInput = syn::parse(input.clone()).unwrap();
+    style_attributes::derive_style_attributes_macro(&ast)
+}
+
 #[proc_macro_derive(UiContext)]
 pub fn ui_context_macro_derive(input: TokenStream) -> TokenStream {
     let ast: DeriveInput = syn::parse(input.clone()).unwrap();
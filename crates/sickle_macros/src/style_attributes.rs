@@ -0,0 +1,461 @@
+use proc_macro::TokenStream;
+use proc_macro2::Ident;
+use quote::{quote, quote_spanned};
+use syn::{AttrStyle, Data, Field, Fields, Type, Visibility};
+
+#[derive(Clone, Debug)]
+struct ComponentAttribute {
+    field: Ident,
+    field_type: Type,
+    command: Ident,
+    cmd_struct_ident: Ident,
+    key: String,
+    lock_const_ident: Ident,
+    animatable: bool,
+    static_style_only: bool,
+}
+
+pub(crate) fn derive_style_attributes_macro(ast: &syn::DeriveInput) -> TokenStream {
+    let name_ident = &ast.ident;
+    let Data::Struct(struct_data) = &ast.data else {
+        return quote_spanned! {
+            name_ident.span() => compile_error!("Unsupported Data type, only Structs with named fields are supported");
+        }
+        .into();
+    };
+
+    let Fields::Named(named_fields) = &struct_data.fields else {
+        return quote_spanned! {
+            name_ident.span() => compile_error!("Unsupported Struct type, only Structs with named fields are supported");
+        }
+        .into();
+    };
+
+    if !ast.generics.params.is_empty() {
+        return quote_spanned! {
+            name_ident.span() => compile_error!("Generic components are not supported");
+        }
+        .into();
+    }
+
+    let attributes: Vec<ComponentAttribute> = named_fields
+        .named
+        .iter()
+        .filter(|field| !has_attribute(field, "skip_style"))
+        .map(|field| parse_field(name_ident, field))
+        .collect();
+
+    for attribute in attributes.iter() {
+        if attribute.animatable && attribute.static_style_only {
+            return quote_spanned! {
+                attribute.field.span() => compile_error!("Attribute cannot be static only and animatable at the same time!");
+            }
+            .into();
+        }
+    }
+
+    let vis = &ast.vis;
+    let lock_consts = prepare_lock_consts(name_ident, &attributes);
+    let style_commands: Vec<proc_macro2::TokenStream> = attributes
+        .iter()
+        .map(|attribute| to_style_command(name_ident, vis, attribute))
+        .collect();
+    let static_builder = prepare_static_builder(name_ident, vis, &attributes);
+    let interactive_builder = prepare_interactive_builder(name_ident, vis, &attributes);
+    let animated_builder = prepare_animated_builder(name_ident, vis, &attributes);
+
+    quote! {
+        #lock_consts
+        #(#style_commands)*
+        #static_builder
+        #interactive_builder
+        #animated_builder
+    }
+    .into()
+}
+
+fn has_attribute(field: &Field, name: &str) -> bool {
+    field
+        .attrs
+        .iter()
+        .any(|attr| attr.style == AttrStyle::Outer && attr.path().is_ident(name))
+}
+
+fn parse_field(component: &Ident, field: &Field) -> ComponentAttribute {
+    // Safe unwrap: named fields always have an ident
+    let field_ident = field.ident.clone().unwrap();
+    let component_snake = to_snake_case(&component.to_string());
+    let command = Ident::new(
+        format!("{}_{}", component_snake, field_ident).as_str(),
+        field_ident.span(),
+    );
+    let cmd_struct_ident = Ident::new(
+        format!(
+            "Set{}{}",
+            component,
+            to_pascal_case(&field_ident.to_string())
+        )
+        .as_str(),
+        field_ident.span(),
+    );
+    let lock_const_ident = Ident::new(
+        format!("LOCKABLE_{}", field_ident.to_string().to_uppercase()).as_str(),
+        field_ident.span(),
+    );
+
+    ComponentAttribute {
+        field: field_ident.clone(),
+        field_type: field.ty.clone(),
+        command,
+        cmd_struct_ident,
+        key: format!("{}::{}", component, field_ident),
+        lock_const_ident,
+        animatable: has_attribute(field, "animatable"),
+        static_style_only: has_attribute(field, "static_style_only"),
+    }
+}
+
+fn to_snake_case(name: &str) -> String {
+    let mut result = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                result.push('_');
+            }
+            result.extend(c.to_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+fn to_pascal_case(name: &str) -> String {
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            // Safe unwrap: empty parts are filtered above
+            let first = chars.next().unwrap();
+            first.to_uppercase().chain(chars).collect::<String>()
+        })
+        .collect()
+}
+
+fn prepare_lock_consts(
+    component: &Ident,
+    attributes: &Vec<ComponentAttribute>,
+) -> proc_macro2::TokenStream {
+    let consts: Vec<proc_macro2::TokenStream> = attributes
+        .iter()
+        .map(|attribute| {
+            let lock_const_ident = &attribute.lock_const_ident;
+            let key = &attribute.key;
+            quote! {
+                pub const #lock_const_ident: LockableStyleAttribute =
+                    LockableStyleAttribute::Custom(concat!(module_path!(), "::", #key));
+            }
+        })
+        .collect();
+
+    quote! {
+        impl #component {
+            #(#consts)*
+        }
+    }
+}
+
+fn to_style_command(
+    component: &Ident,
+    vis: &Visibility,
+    attribute: &ComponentAttribute,
+) -> proc_macro2::TokenStream {
+    let field = &attribute.field;
+    let field_type = &attribute.field_type;
+    let command = &attribute.command;
+    let cmd_struct_ident = &attribute.cmd_struct_ident;
+    let lock_const_ident = &attribute.lock_const_ident;
+    let attr_name = attribute.key.clone();
+    let component_name = component.to_string();
+
+    let extension_ident = Ident::new(
+        format!("{}Ext", cmd_struct_ident).as_str(),
+        cmd_struct_ident.span(),
+    );
+    let extension_unchecked_ident = Ident::new(
+        format!("{}UncheckedExt", cmd_struct_ident).as_str(),
+        cmd_struct_ident.span(),
+    );
+
+    quote! {
+        #vis struct #cmd_struct_ident {
+            pub #field: #field_type,
+            pub check_lock: bool,
+        }
+
+        impl bevy::ecs::system::EntityCommand for #cmd_struct_ident {
+            fn apply(self, entity: Entity, world: &mut World) {
+                if LockedStyleAttributes::check_write(
+                    entity,
+                    #component::#lock_const_ident,
+                    self.check_lock,
+                    world,
+                ) {
+                    warn!(
+                        "Failed to style {} property on entity {:?}: Attribute locked!",
+                        #attr_name,
+                        entity
+                    );
+                    return;
+                }
+
+                let Some(mut component) = world.get_mut::<#component>(entity) else {
+                    warn!(
+                        "Failed to set {} property on entity {:?}: No {} component found!",
+                        #attr_name,
+                        entity,
+                        #component_name
+                    );
+                    return;
+                };
+
+                if component.#field != self.#field {
+                    component.#field = self.#field;
+                }
+            }
+        }
+
+        #vis trait #extension_ident {
+            fn #command(&mut self, #field: #field_type) -> &mut Self;
+        }
+
+        impl #extension_ident for UiStyle<'_> {
+            fn #command(&mut self, #field: #field_type) -> &mut Self {
+                self.entity_commands().add(#cmd_struct_ident {
+                    #field,
+                    check_lock: true,
+                });
+                self
+            }
+        }
+
+        #vis trait #extension_unchecked_ident {
+            fn #command(&mut self, #field: #field_type) -> &mut Self;
+        }
+
+        impl #extension_unchecked_ident for UiStyleUnchecked<'_> {
+            fn #command(&mut self, #field: #field_type) -> &mut Self {
+                self.entity_commands().add(#cmd_struct_ident {
+                    #field,
+                    check_lock: false,
+                });
+                self
+            }
+        }
+    }
+}
+
+fn prepare_static_builder(
+    component: &Ident,
+    vis: &Visibility,
+    attributes: &Vec<ComponentAttribute>,
+) -> proc_macro2::TokenStream {
+    let trait_ident = Ident::new(
+        format!("{}StyleBuilderExt", component).as_str(),
+        component.span(),
+    );
+
+    let signatures: Vec<proc_macro2::TokenStream> = attributes
+        .iter()
+        .map(|attribute| {
+            let command = &attribute.command;
+            let field_type = &attribute.field_type;
+            quote! {
+                fn #command(&mut self, #command: impl Into<#field_type>) -> &mut Self;
+            }
+        })
+        .collect();
+
+    let builder_fns: Vec<proc_macro2::TokenStream> = attributes
+        .iter()
+        .map(|attribute| {
+            let field = &attribute.field;
+            let field_type = &attribute.field_type;
+            let command = &attribute.command;
+            let cmd_struct_ident = &attribute.cmd_struct_ident;
+            let key = &attribute.key;
+            quote! {
+                fn #command(&mut self, #command: impl Into<#field_type>) -> &mut Self {
+                    let #field: #field_type = #command.into();
+                    self.add(DynamicStyleAttribute::Static(StaticStyleAttribute::Custom(
+                        CustomStaticStyleAttribute::keyed(
+                            concat!(module_path!(), "::", #key),
+                            move |entity, world| {
+                                bevy::ecs::system::EntityCommand::apply(
+                                    #cmd_struct_ident {
+                                        #field: #field.clone(),
+                                        check_lock: true,
+                                    },
+                                    entity,
+                                    world,
+                                );
+                            },
+                        ),
+                    )));
+
+                    self
+                }
+            }
+        })
+        .collect();
+
+    quote! {
+        #vis trait #trait_ident {
+            #(#signatures)*
+        }
+
+        impl #trait_ident for StyleBuilder {
+            #(#builder_fns)*
+        }
+    }
+}
+
+fn prepare_interactive_builder(
+    component: &Ident,
+    vis: &Visibility,
+    attributes: &Vec<ComponentAttribute>,
+) -> proc_macro2::TokenStream {
+    let trait_ident = Ident::new(
+        format!("{}InteractiveStyleBuilderExt", component).as_str(),
+        component.span(),
+    );
+    let attributes = attributes.iter().filter(|v| !v.static_style_only);
+
+    let signatures: Vec<proc_macro2::TokenStream> = attributes
+        .clone()
+        .map(|attribute| {
+            let command = &attribute.command;
+            let field_type = &attribute.field_type;
+            quote! {
+                fn #command(&mut self, bundle: impl Into<InteractiveVals<#field_type>>) -> &mut Self;
+            }
+        })
+        .collect();
+
+    let builder_fns: Vec<proc_macro2::TokenStream> = attributes
+        .map(|attribute| {
+            let field = &attribute.field;
+            let field_type = &attribute.field_type;
+            let command = &attribute.command;
+            let cmd_struct_ident = &attribute.cmd_struct_ident;
+            let key = &attribute.key;
+            quote! {
+                fn #command(&mut self, bundle: impl Into<InteractiveVals<#field_type>>) -> &mut Self {
+                    let bundle: InteractiveVals<#field_type> = bundle.into();
+                    self.style_builder.add(DynamicStyleAttribute::Interactive(
+                        InteractiveStyleAttribute::Custom(CustomInteractiveStyleAttribute::keyed(
+                            concat!(module_path!(), "::", #key),
+                            move |entity, flux_interaction, world| {
+                                bevy::ecs::system::EntityCommand::apply(
+                                    #cmd_struct_ident {
+                                        #field: bundle.to_value(flux_interaction),
+                                        check_lock: true,
+                                    },
+                                    entity,
+                                    world,
+                                );
+                            },
+                        )),
+                    ));
+
+                    self
+                }
+            }
+        })
+        .collect();
+
+    quote! {
+        #vis trait #trait_ident {
+            #(#signatures)*
+        }
+
+        impl #trait_ident for InteractiveStyleBuilder<'_> {
+            #(#builder_fns)*
+        }
+    }
+}
+
+fn prepare_animated_builder(
+    component: &Ident,
+    vis: &Visibility,
+    attributes: &Vec<ComponentAttribute>,
+) -> proc_macro2::TokenStream {
+    let trait_ident = Ident::new(
+        format!("{}AnimatedStyleBuilderExt", component).as_str(),
+        component.span(),
+    );
+    let attributes = attributes.iter().filter(|v| v.animatable);
+
+    let signatures: Vec<proc_macro2::TokenStream> = attributes
+        .clone()
+        .map(|attribute| {
+            let command = &attribute.command;
+            let field_type = &attribute.field_type;
+            quote! {
+                fn #command(
+                    &mut self,
+                    bundle: impl Into<AnimatedVals<#field_type>>,
+                ) -> &mut AnimationSettings;
+            }
+        })
+        .collect();
+
+    let builder_fns: Vec<proc_macro2::TokenStream> = attributes
+        .map(|attribute| {
+            let field = &attribute.field;
+            let field_type = &attribute.field_type;
+            let command = &attribute.command;
+            let cmd_struct_ident = &attribute.cmd_struct_ident;
+            let key = &attribute.key;
+            quote! {
+                fn #command(
+                    &mut self,
+                    bundle: impl Into<AnimatedVals<#field_type>>,
+                ) -> &mut AnimationSettings {
+                    let bundle: AnimatedVals<#field_type> = bundle.into();
+                    let attribute = DynamicStyleAttribute::Animated {
+                        attribute: AnimatedStyleAttribute::Custom(
+                            CustomAnimatedStyleAttribute::keyed(
+                                concat!(module_path!(), "::", #key),
+                                move |entity, current_state, world| {
+                                    bevy::ecs::system::EntityCommand::apply(
+                                        #cmd_struct_ident {
+                                            #field: bundle.to_value(&current_state),
+                                            check_lock: true,
+                                        },
+                                        entity,
+                                        world,
+                                    );
+                                },
+                            ),
+                        ),
+                        controller: DynamicStyleController::default(),
+                    };
+
+                    self.add_and_extract_animation(attribute)
+                }
+            }
+        })
+        .collect();
+
+    quote! {
+        #vis trait #trait_ident {
+            #(#signatures)*
+        }
+
+        impl #trait_ident for AnimatedStyleBuilder<'_> {
+            #(#builder_fns)*
+        }
+    }
+}
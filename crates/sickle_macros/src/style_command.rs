@@ -35,15 +35,13 @@ fn parse_lock_attribute(
 
             let stylable_attr = list.tokens.clone();
             return Ok(quote! {
-                if let Some(locked_attrs) = world.get::<LockedStyleAttributes>(entity){
-                    if locked_attrs.contains(#stylable_attr){
-                        warn!(
-                            "Failed to style {:?} property on entity {:?}: Attribute locked!",
-                            #stylable_attr,
-                            entity
-                        );
-                        return;
-                    }
+                if LockedStyleAttributes::is_locked(entity, #stylable_attr, world) {
+                    warn!(
+                        "Failed to style {:?} property on entity {:?}: Attribute locked!",
+                        #stylable_attr,
+                        entity
+                    );
+                    return;
                 }
             });
         } else {
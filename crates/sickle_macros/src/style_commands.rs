@@ -235,6 +235,8 @@ fn prepare_lockable_attribute(style_attributes: &Vec<StyleAttribute>) -> proc_ma
         #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
         pub enum LockableStyleAttribute {
             #(#base_variants)*
+            /// Attribute of a user component, see `#[derive(StyleAttributes)]`
+            Custom(&'static str),
         }
     }
 }
@@ -253,6 +255,11 @@ fn prepare_static_style_attribute(
         .collect();
     let builder_fns: Vec<proc_macro2::TokenStream> =
         variants.clone().map(to_static_style_builder_fn).collect();
+    let lockable_variants: Vec<proc_macro2::TokenStream> = variants
+        .clone()
+        .filter(|v| !v.skip_lockable_enum)
+        .map(to_static_style_lockable_variant)
+        .collect();
 
     quote! {
         #[derive(Clone, Debug)]
@@ -265,7 +272,7 @@ fn prepare_static_style_attribute(
             fn logical_eq(&self, other: &Self) -> bool {
                 match (self, other) {
                     #(#eq_variants)*
-                    (Self::Custom(l0), Self::Custom(r0)) => l0 == r0,
+                    (Self::Custom(l0), Self::Custom(r0)) => l0.logical_eq(r0),
                     _ => false,
                 }
             }
@@ -280,6 +287,15 @@ fn prepare_static_style_attribute(
                     }
                 }
             }
+
+            /// The lock guarding the attribute, `None` if it cannot be locked
+            pub fn lockable_attribute(&self) -> Option<LockableStyleAttribute> {
+                match self {
+                    #(#lockable_variants)*
+                    Self::Custom(callback) => callback.key().map(LockableStyleAttribute::Custom),
+                    _ => None,
+                }
+            }
         }
 
         impl StyleBuilder {
@@ -316,7 +332,7 @@ fn prepare_interactive_style_attribute(
             fn logical_eq(&self, other: &Self) -> bool {
                 match (self, other) {
                     #(#eq_variants)*
-                    (Self::Custom(l0), Self::Custom(r0)) => l0 == r0,
+                    (Self::Custom(l0), Self::Custom(r0)) => l0.logical_eq(r0),
                     _ => false,
                 }
             }
@@ -330,6 +346,15 @@ fn prepare_interactive_style_attribute(
                 }
             }
 
+            /// Resolves the value for the interaction. Returns `None` for custom attributes,
+            /// which can only be applied via `apply`.
+            pub fn resolve(&self, flux_interaction: FluxInteraction) -> Option<StaticStyleAttribute> {
+                match self {
+                    Self::Custom(_) => None,
+                    _ => Some(self.to_attribute(flux_interaction)),
+                }
+            }
+
             pub fn apply(&self, flux_interaction: FluxInteraction, ui_style: &mut UiStyle) {
                 match self {
                     Self::Custom(callback) => {
@@ -379,7 +404,7 @@ fn prepare_animated_style_attribute(
             fn logical_eq(&self, other: &Self) -> bool {
                 match (self, other) {
                     #(#eq_variants)*
-                    (Self::Custom(l0), Self::Custom(r0)) => l0 == r0,
+                    (Self::Custom(l0), Self::Custom(r0)) => l0.logical_eq(r0),
                     _ => false,
                 }
             }
@@ -396,6 +421,15 @@ fn prepare_animated_style_attribute(
                 }
             }
 
+            /// Resolves the value for the animation state. Returns `None` for custom attributes,
+            /// which can only be applied via `apply`.
+            pub fn resolve(&self, current_state: &AnimationState) -> Option<StaticStyleAttribute> {
+                match self {
+                    Self::Custom(_) => None,
+                    _ => Some(self.to_attribute(current_state)),
+                }
+            }
+
             pub fn apply(
                 &self,
                 current_state: &AnimationState,
@@ -464,6 +498,9 @@ fn prepare_enum_equivalence(style_attributes: &Vec<StyleAttribute>) -> proc_macr
             fn logical_eq(&self, other: &StaticStyleAttribute) -> bool {
                 match (self, other) {
                     #(#interactive_to_static)*
+                    (Self::Custom(l0), StaticStyleAttribute::Custom(r0)) => {
+                        l0.key().is_some() && l0.key() == r0.key()
+                    }
                     _ => false,
                 }
             }
@@ -472,6 +509,9 @@ fn prepare_enum_equivalence(style_attributes: &Vec<StyleAttribute>) -> proc_macr
             fn logical_eq(&self, other: &InteractiveStyleAttribute) -> bool {
                 match (self, other) {
                     #(#static_to_interactive)*
+                    (Self::Custom(l0), InteractiveStyleAttribute::Custom(r0)) => {
+                        l0.key().is_some() && l0.key() == r0.key()
+                    }
                     _ => false,
                 }
             }
@@ -480,6 +520,9 @@ fn prepare_enum_equivalence(style_attributes: &Vec<StyleAttribute>) -> proc_macr
             fn logical_eq(&self, other: &InteractiveStyleAttribute) -> bool {
                 match (self, other) {
                     #(#animated_to_interactive)*
+                    (Self::Custom(l0), InteractiveStyleAttribute::Custom(r0)) => {
+                        l0.key().is_some() && l0.key() == r0.key()
+                    }
                     _ => false,
                 }
             }
@@ -488,6 +531,9 @@ fn prepare_enum_equivalence(style_attributes: &Vec<StyleAttribute>) -> proc_macr
             fn logical_eq(&self, other: &AnimatedStyleAttribute) -> bool {
                 match (self, other) {
                     #(#interactive_to_animated)*
+                    (Self::Custom(l0), AnimatedStyleAttribute::Custom(r0)) => {
+                        l0.key().is_some() && l0.key() == r0.key()
+                    }
                     _ => false,
                 }
             }
@@ -496,6 +542,9 @@ fn prepare_enum_equivalence(style_attributes: &Vec<StyleAttribute>) -> proc_macr
             fn logical_eq(&self, other: &StaticStyleAttribute) -> bool {
                 match (self, other) {
                     #(#animated_to_static)*
+                    (Self::Custom(l0), StaticStyleAttribute::Custom(r0)) => {
+                        l0.key().is_some() && l0.key() == r0.key()
+                    }
                     _ => false,
                 }
             }
@@ -504,6 +553,9 @@ fn prepare_enum_equivalence(style_attributes: &Vec<StyleAttribute>) -> proc_macr
             fn logical_eq(&self, other: &AnimatedStyleAttribute) -> bool {
                 match (self, other) {
                     #(#static_to_animated)*
+                    (Self::Custom(l0), AnimatedStyleAttribute::Custom(r0)) => {
+                        l0.key().is_some() && l0.key() == r0.key()
+                    }
                     _ => false,
                 }
             }
@@ -599,6 +651,13 @@ fn to_static_style_apply_variant(style_attribute: &StyleAttribute) -> proc_macro
     }
 }
 
+fn to_static_style_lockable_variant(style_attribute: &StyleAttribute) -> proc_macro2::TokenStream {
+    let ident = &style_attribute.ident;
+    quote! {
+        Self::#ident(_) => Some(LockableStyleAttribute::#ident),
+    }
+}
+
 fn to_interactive_style_appl_variant(style_attribute: &StyleAttribute) -> proc_macro2::TokenStream {
     let ident = &style_attribute.ident;
     quote! {
@@ -725,17 +784,18 @@ fn to_ui_style_command_impl(style_attribute: &StyleAttribute) -> proc_macro2::To
     let check_lock = match style_attribute.skip_lockable_enum {
         true => proc_macro2::TokenStream::new(),
         false => quote! {
-            if self.check_lock {
-                if let Some(locked_attrs) = world.get::<LockedStyleAttributes>(entity) {
-                    if locked_attrs.contains(LockableStyleAttribute::#ident) {
-                        warn!(
-                            "Failed to style {} property on entity {:?}: Attribute locked!",
-                            #target_attr_name,
-                            entity
-                        );
-                        return;
-                    }
-                }
+            if LockedStyleAttributes::check_write(
+                entity,
+                LockableStyleAttribute::#ident,
+                self.check_lock,
+                world,
+            ) {
+                warn!(
+                    "Failed to style {} property on entity {:?}: Attribute locked!",
+                    #target_attr_name,
+                    entity
+                );
+                return;
             }
         },
     };
@@ -1,4 +1,5 @@
 use bevy::{
+    math::Vec2,
     render::color::Color,
     ui::{UiRect, Val},
 };
@@ -40,6 +41,12 @@ impl Lerp for Color {
     }
 }
 
+impl Lerp for Vec2 {
+    fn lerp(&self, to: Self, t: f32) -> Self {
+        Vec2::new(self.x.lerp(to.x, t), self.y.lerp(to.y, t))
+    }
+}
+
 impl Lerp for Val {
     fn lerp(&self, to: Self, t: f32) -> Self {
         // We can only LERP between values with the same scale
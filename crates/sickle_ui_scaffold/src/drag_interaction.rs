@@ -1,9 +1,19 @@
+use std::{fmt, sync::Arc};
+
 use bevy::prelude::*;
-use bevy::ui::RelativeCursorPosition;
+use bevy::ui::{FocusPolicy, RelativeCursorPosition};
 use bevy::window::{CursorGrabMode, PrimaryWindow};
 use bevy_reflect::Reflect;
 
-use crate::flux_interaction::{FluxInteraction, FluxInteractionUpdate};
+use crate::{
+    flux_interaction::{FluxInteraction, FluxInteractionUpdate},
+    node_shape::NodeShape,
+    theme::pseudo_state::{PseudoState, PseudoStates},
+    ui_builder::{UiBuilder, UiBuilderExt, UiContextRoot},
+    ui_commands::ManagePseudoStateExt,
+};
+
+const DRAG_PREVIEW_Z_INDEX: i32 = 100020;
 
 pub struct DragInteractionPlugin;
 
@@ -15,11 +25,14 @@ impl Plugin for DragInteractionPlugin {
                 (
                     update_drag_progress,
                     update_drag_state,
+                    update_dragged_pseudo_state,
+                    update_drag_preview,
                     update_cursor_confinement_from_drag.run_if(is_windows_os),
                 )
                     .chain()
                     .in_set(DraggableUpdate),
-            );
+            )
+            .add_systems(PostUpdate, delete_orphaned_drag_preview_nodes);
     }
 }
 
@@ -34,6 +47,9 @@ pub struct Draggable {
     pub position: Option<Vec2>,
     pub diff: Option<Vec2>,
     pub source: DragSource,
+    /// Mouse button that started the drag, `None` for touches
+    pub button: Option<MouseButton>,
+    held_for: f32,
 }
 
 impl Draggable {
@@ -41,6 +57,131 @@ impl Draggable {
         self.origin = None;
         self.position = None;
         self.diff = Vec2::default().into();
+        self.held_for = 0.;
+    }
+
+    fn start(&mut self, source: DragSource, button: Option<MouseButton>, position: Vec2) {
+        self.state = DragState::MaybeDragged;
+        self.source = source;
+        self.button = button;
+        self.origin = position.into();
+        self.position = position.into();
+        self.diff = Vec2::default().into();
+        self.held_for = 0.;
+    }
+
+    /// Buttons other than the primary one are not tracked by `Interaction`, they are polled
+    fn polled_button(&self) -> Option<MouseButton> {
+        match (self.source, self.button) {
+            (DragSource::Mouse, Some(button)) if button != MouseButton::Left => button.into(),
+            _ => None,
+        }
+    }
+
+    /// Whether the press that started the drag is still held. The primary button and touches
+    /// are tracked by `FluxInteraction`.
+    fn is_held(&self, interaction: &FluxInteraction, mouse: &ButtonInput<MouseButton>) -> bool {
+        match self.polled_button() {
+            Some(button) => mouse.pressed(button),
+            None => *interaction == FluxInteraction::Pressed,
+        }
+    }
+
+    fn end(&mut self) {
+        if self.state == DragState::DragStart || self.state == DragState::Dragging {
+            self.state = DragState::DragEnd;
+        } else if self.state == DragState::MaybeDragged {
+            self.state = DragState::Inactive;
+            self.clear();
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Reflect)]
+#[reflect]
+pub enum DragAxis {
+    #[default]
+    Free,
+    Horizontal,
+    Vertical,
+}
+
+/// Controls when and how a `Draggable` on the same entity is dragged.
+///
+/// Without it, a drag starts on the first movement of the primary button or a touch.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct DraggableConfig {
+    /// Distance in logical pixels the pointer must move before the drag starts
+    pub threshold: f32,
+    /// Seconds (in real time) the press must be held before the drag starts
+    pub delay: f32,
+    /// Mouse buttons that start a drag
+    pub buttons: Vec<MouseButton>,
+    pub touch: bool,
+    pub axis: DragAxis,
+    /// While held, locks the drag to the axis it moved along the most
+    pub axis_lock_key: Option<KeyCode>,
+    /// Grid step of the drag position, relative to where the drag started
+    pub snap: Option<Vec2>,
+}
+
+impl Default for DraggableConfig {
+    fn default() -> Self {
+        Self {
+            threshold: 0.,
+            delay: 0.,
+            buttons: vec![MouseButton::Left],
+            touch: true,
+            axis: DragAxis::Free,
+            axis_lock_key: None,
+            snap: None,
+        }
+    }
+}
+
+impl DraggableConfig {
+    /// Threshold that keeps clicks from turning into drags on small pointer jitter
+    pub const CLICK_THRESHOLD: f32 = 5.;
+
+    pub fn with_axis(axis: DragAxis) -> Self {
+        Self { axis, ..default() }
+    }
+
+    /// For draggables that are also clicked, like tabs or panel titles
+    pub fn clickable() -> Self {
+        Self {
+            threshold: DraggableConfig::CLICK_THRESHOLD,
+            ..default()
+        }
+    }
+
+    /// Applies the axis and snapping constraints to a movement from the drag origin
+    pub fn constrain(&self, movement: Vec2, keys: &ButtonInput<KeyCode>) -> Vec2 {
+        let mut movement = match self.axis {
+            DragAxis::Free => movement,
+            DragAxis::Horizontal => Vec2::new(movement.x, 0.),
+            DragAxis::Vertical => Vec2::new(0., movement.y),
+        };
+
+        if self.axis_lock_key.is_some_and(|key| keys.pressed(key)) {
+            if movement.x.abs() >= movement.y.abs() {
+                movement.y = 0.;
+            } else {
+                movement.x = 0.;
+            }
+        }
+
+        if let Some(step) = self.snap {
+            if step.x > 0. {
+                movement.x = (movement.x / step.x).round() * step.x;
+            }
+            if step.y > 0. {
+                movement.y = (movement.y / step.y).round() * step.y;
+            }
+        }
+
+        movement
     }
 }
 
@@ -64,6 +205,105 @@ pub enum DragSource {
     Touch(u64),
 }
 
+/// Spawns the content of a drag preview into its container
+pub type DragPreviewBuilder = Arc<dyn Fn(&mut UiBuilder<Entity>) + Send + Sync>;
+
+#[derive(Clone, Default)]
+pub enum DragPreviewContent {
+    /// A childless copy of the source node's size, background, border, image and `NodeShape`
+    #[default]
+    Ghost,
+    /// Custom content, spawned into an empty container
+    Builder(DragPreviewBuilder),
+}
+
+impl fmt::Debug for DragPreviewContent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ghost => f.write_str("Ghost"),
+            Self::Builder(_) => f.write_str("Builder"),
+        }
+    }
+}
+
+/// Shows a preview following the pointer while the `Draggable` is dragged, instead of
+/// leaving the user without visual feedback.
+///
+/// The preview is spawned under the nearest `UiContextRoot` on `DragState::DragStart` and
+/// despawned when the drag ends or is canceled.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct DragPreview {
+    #[reflect(ignore)]
+    pub content: DragPreviewContent,
+    /// Opacity of the ghost copy
+    pub opacity: f32,
+    /// Offset of the preview's top left corner from the pointer.
+    /// When not set, the preview keeps the point where the source was grabbed under the pointer.
+    pub offset: Option<Vec2>,
+    preview: Option<Entity>,
+    grab_offset: Vec2,
+}
+
+impl Default for DragPreview {
+    fn default() -> Self {
+        Self {
+            content: Default::default(),
+            opacity: 0.5,
+            offset: None,
+            preview: None,
+            grab_offset: Vec2::ZERO,
+        }
+    }
+}
+
+impl DragPreview {
+    pub fn builder(
+        spawn_children: impl Fn(&mut UiBuilder<Entity>) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            content: DragPreviewContent::Builder(Arc::new(spawn_children)),
+            ..default()
+        }
+    }
+
+    pub fn with_offset(self, offset: Vec2) -> Self {
+        Self {
+            offset: offset.into(),
+            ..self
+        }
+    }
+
+    pub fn preview(&self) -> Option<Entity> {
+        self.preview
+    }
+
+    fn position(&self, pointer: Vec2) -> Vec2 {
+        pointer + self.offset.unwrap_or(-self.grab_offset)
+    }
+}
+
+/// Marks the preview node of a dragged entity
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+pub struct DragPreviewNode {
+    source: Entity,
+}
+
+impl Default for DragPreviewNode {
+    fn default() -> Self {
+        Self {
+            source: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+impl DragPreviewNode {
+    pub fn source(&self) -> Entity {
+        self.source
+    }
+}
+
 /// Returns `cfg!(target_os = "windows")`
 fn is_windows_os() -> bool {
     cfg!(target_os = "windows")
@@ -77,10 +317,9 @@ fn update_cursor_confinement_from_drag(
         return;
     };
 
-    if let Some(_) = q_draggable
-        .iter()
-        .find(|&draggable| draggable.state == DragState::DragStart)
-    {
+    if let Some(_) = q_draggable.iter().find(|&draggable| {
+        draggable.state == DragState::DragStart && draggable.source == DragSource::Mouse
+    }) {
         window.cursor.grab_mode = CursorGrabMode::Confined;
     } else if let Some(_) = q_draggable.iter().find(|&draggable| {
         draggable.state == DragState::DragEnd || draggable.state == DragState::DragCanceled
@@ -89,25 +328,36 @@ fn update_cursor_confinement_from_drag(
     }
 }
 
+type DragProgressSource<'a> = (
+    &'a mut Draggable,
+    Option<&'a DraggableConfig>,
+    &'a FluxInteraction,
+    &'a RelativeCursorPosition,
+    &'a Node,
+    &'a GlobalTransform,
+);
+
 // TODO: Consider using MouseMotion and TouchInput events directly
 fn update_drag_progress(
-    mut q_draggable: Query<(
-        &mut Draggable,
-        &FluxInteraction,
-        &RelativeCursorPosition,
-        &Node,
-        &GlobalTransform,
-    )>,
+    mut q_draggable: Query<DragProgressSource>,
     r_touches: Res<Touches>,
+    r_mouse: Res<ButtonInput<MouseButton>>,
     r_keys: Res<ButtonInput<KeyCode>>,
+    ui_scale: Res<UiScale>,
+    time: Res<Time<Real>>,
 ) {
-    for (mut draggable, flux_interaction, relcurpos, node, global_trans) in &mut q_draggable {
+    let default_config = DraggableConfig::default();
+
+    for (mut draggable, config, flux_interaction, relcurpos, node, global_trans) in &mut q_draggable
+    {
+        let config = config.unwrap_or(&default_config);
+
         if draggable.state == DragState::DragEnd {
             draggable.state = DragState::Inactive;
             draggable.clear();
         } else if draggable.state == DragState::DragCanceled {
             draggable.state = DragState::Inactive;
-        } else if *flux_interaction == FluxInteraction::Pressed
+        } else if draggable.is_held(flux_interaction, &r_mouse)
             && (draggable.state == DragState::MaybeDragged
                 || draggable.state == DragState::DragStart
                 || draggable.state == DragState::Dragging)
@@ -135,77 +385,334 @@ fn update_drag_progress(
                     }
                 }
                 DragSource::Touch(id) => match r_touches.get_pressed(id) {
-                    Some(touch) => touch.position().into(),
+                    Some(touch) => (touch.position() / ui_scale.0).into(),
                     None => None,
                 },
             };
 
-            if let (Some(old_position), Some(updated_position)) = (draggable.position, new_position)
+            if draggable.state == DragState::MaybeDragged {
+                draggable.held_for += time.delta_seconds();
+            }
+
+            let (Some(origin), Some(old_position), Some(updated_position)) =
+                (draggable.origin, draggable.position, new_position)
+            else {
+                continue;
+            };
+
+            if draggable.state == DragState::MaybeDragged
+                && (updated_position.distance(origin) < config.threshold
+                    || draggable.held_for < config.delay)
             {
-                let diff = updated_position - old_position;
+                continue;
+            }
 
-                // No tolerance threshold, just move
-                if diff.length_squared() > 0. {
-                    if draggable.state == DragState::MaybeDragged {
-                        draggable.state = DragState::DragStart;
-                    }
+            let updated_position = origin + config.constrain(updated_position - origin, &r_keys);
+            let diff = updated_position - old_position;
 
-                    draggable.position = updated_position.into();
-                    draggable.diff = diff.into();
+            if diff.length_squared() > 0. {
+                if draggable.state == DragState::MaybeDragged {
+                    draggable.state = DragState::DragStart;
                 }
+
+                draggable.position = updated_position.into();
+                draggable.diff = diff.into();
             }
         }
     }
 }
 
+type DragStateSource<'a> = (
+    &'a mut Draggable,
+    Option<&'a DraggableConfig>,
+    Ref<'a, FluxInteraction>,
+    &'a Interaction,
+    &'a RelativeCursorPosition,
+    &'a Node,
+    &'a GlobalTransform,
+);
+
 fn update_drag_state(
-    mut q_draggable: Query<
-        (
-            &mut Draggable,
-            &FluxInteraction,
-            &RelativeCursorPosition,
-            &Node,
-            &GlobalTransform,
-        ),
-        Changed<FluxInteraction>,
-    >,
+    mut q_draggable: Query<DragStateSource>,
     r_touches: Res<Touches>,
+    r_mouse: Res<ButtonInput<MouseButton>>,
+    ui_scale: Res<UiScale>,
 ) {
-    for (mut draggable, flux_interaction, relcurpos, node, global_trans) in &mut q_draggable {
-        if *flux_interaction == FluxInteraction::Pressed
+    let default_config = DraggableConfig::default();
+
+    for (mut draggable, config, flux_interaction, interaction, relcurpos, node, global_trans) in
+        &mut q_draggable
+    {
+        let config = config.unwrap_or(&default_config);
+        let node_rect = node.logical_rect(global_trans);
+        // No window method: Cursor is at the Node's top left screenspace rect.min,
+        // plus the relative screenspace position in the Node, which is relcurpos times the node rect's size
+        let cursor_position = relcurpos
+            .normalized
+            .map(|relative_cursor_pos| node_rect.min + (node_rect.size() * relative_cursor_pos));
+
+        if flux_interaction.is_changed()
+            && *flux_interaction == FluxInteraction::Pressed
             && draggable.state != DragState::MaybeDragged
         {
-            let mut drag_source = DragSource::Mouse;
-
-            // No window method: Cursor is at the Node's top left screenspace rect.min,
-            // plus the relative screenspace position in the Node, which is relcurpos times the node rect's size
-            let mut initial_position;
-            if let Some(relative_cursor_pos) = relcurpos.normalized {
-                let node_rect = node.logical_rect(global_trans);
-                initial_position = Some(node_rect.min + (node_rect.size() * relative_cursor_pos))
-            } else {
-                initial_position = None;
+            // Touches also move the cursor position of the UI, so check them first
+            let touch = r_touches
+                .iter_just_pressed()
+                .map(|touch| (touch.id(), touch.position() / ui_scale.0))
+                .find(|(_, position)| node_rect.contains(*position));
+
+            let (drag_source, button, initial_position) = match (touch, cursor_position) {
+                (Some((id, position)), _) if config.touch => {
+                    (DragSource::Touch(id), None, position)
+                }
+                (Some(_), _) => continue,
+                (None, Some(position)) if config.buttons.contains(&MouseButton::Left) => {
+                    (DragSource::Mouse, MouseButton::Left.into(), position)
+                }
+                // Pressed without a pointer, e.g. through navigation
+                _ => continue,
+            };
+
+            draggable.start(drag_source, button, initial_position);
+        } else if flux_interaction.is_changed()
+            && (*flux_interaction == FluxInteraction::Released
+                || *flux_interaction == FluxInteraction::PressCanceled)
+            && draggable.polled_button().is_none()
+        {
+            draggable.end();
+        } else {
+            // Other buttons are not tracked by Interaction, poll them directly
+            if let Some(button) = draggable.polled_button() {
+                if draggable.state != DragState::Inactive && r_mouse.just_released(button) {
+                    draggable.end();
+                    continue;
+                }
             }
 
-            if initial_position.is_none() {
-                initial_position = r_touches.first_pressed_position();
-                drag_source = DragSource::Touch(r_touches.iter().next().unwrap().id());
+            if draggable.state != DragState::Inactive || *interaction != Interaction::Hovered {
+                continue;
             }
 
-            draggable.state = DragState::MaybeDragged;
-            draggable.source = drag_source;
-            draggable.origin = initial_position;
-            draggable.position = initial_position;
-            draggable.diff = Vec2::default().into();
-        } else if *flux_interaction == FluxInteraction::Released
-            || *flux_interaction == FluxInteraction::PressCanceled
-        {
-            if draggable.state == DragState::DragStart || draggable.state == DragState::Dragging {
-                draggable.state = DragState::DragEnd;
-            } else if draggable.state == DragState::MaybeDragged {
-                draggable.state = DragState::Inactive;
-                draggable.clear();
+            let Some(position) = cursor_position else {
+                continue;
+            };
+            let pressed = config
+                .buttons
+                .iter()
+                .find(|&&button| button != MouseButton::Left && r_mouse.just_pressed(button));
+
+            if let Some(button) = pressed {
+                draggable.start(DragSource::Mouse, (*button).into(), position);
             }
         }
     }
 }
+
+/// Every `Draggable` has `PseudoState::Dragged` from `DragState::DragStart` until the drag ends
+/// or is canceled
+fn update_dragged_pseudo_state(
+    q_draggables: Query<(Entity, &Draggable, Option<&PseudoStates>), Changed<Draggable>>,
+    mut commands: Commands,
+) {
+    for (entity, draggable, pseudo_states) in &q_draggables {
+        let is_dragged = pseudo_states.is_some_and(|states| states.has(&PseudoState::Dragged));
+        match draggable.state {
+            DragState::DragStart => {
+                if !is_dragged {
+                    commands
+                        .entity(entity)
+                        .add_pseudo_state(PseudoState::Dragged);
+                }
+            }
+            DragState::DragEnd | DragState::DragCanceled | DragState::Inactive => {
+                if is_dragged {
+                    commands
+                        .entity(entity)
+                        .remove_pseudo_state(PseudoState::Dragged);
+                }
+            }
+            DragState::MaybeDragged | DragState::Dragging => (),
+        }
+    }
+}
+
+type DragPreviewSource<'a> = (
+    Entity,
+    &'a Draggable,
+    &'a mut DragPreview,
+    &'a Node,
+    &'a GlobalTransform,
+    &'a Style,
+    Option<&'a BackgroundColor>,
+    Option<&'a BorderColor>,
+    Option<&'a UiImage>,
+    Option<&'a NodeShape>,
+);
+
+fn update_drag_preview(
+    mut q_draggables: Query<DragPreviewSource, (Changed<Draggable>, Without<DragPreviewNode>)>,
+    mut q_preview_styles: Query<&mut Style, With<DragPreviewNode>>,
+    q_parents: Query<&Parent>,
+    q_context_roots: Query<(), With<UiContextRoot>>,
+    mut commands: Commands,
+) {
+    for (
+        entity,
+        draggable,
+        mut drag_preview,
+        node,
+        transform,
+        style,
+        background_color,
+        border_color,
+        image,
+        node_shape,
+    ) in &mut q_draggables
+    {
+        match draggable.state {
+            DragState::DragStart => {
+                let Some(position) = draggable.position else {
+                    continue;
+                };
+
+                if let Some(preview) = drag_preview.preview {
+                    commands.entity(preview).despawn_recursive();
+                }
+
+                let node_rect = node.logical_rect(transform);
+                drag_preview.grab_offset = draggable.origin.unwrap_or(position) - node_rect.min;
+                let preview_position = drag_preview.position(position);
+
+                let mut root_node = entity;
+                for ancestor in q_parents.iter_ancestors(entity) {
+                    root_node = ancestor;
+                    if q_context_roots.contains(ancestor) {
+                        break;
+                    }
+                }
+
+                let mut preview_style = Style {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(preview_position.x),
+                    top: Val::Px(preview_position.y),
+                    ..default()
+                };
+                let node_bundle = NodeBundle {
+                    focus_policy: FocusPolicy::Pass,
+                    z_index: ZIndex::Global(DRAG_PREVIEW_Z_INDEX),
+                    ..default()
+                };
+
+                let mut builder = commands.ui_builder(root_node);
+                let mut preview_builder = match &drag_preview.content {
+                    DragPreviewContent::Ghost => {
+                        let opacity = drag_preview.opacity;
+                        preview_style.width = Val::Px(node.size().x);
+                        preview_style.height = Val::Px(node.size().y);
+                        preview_style.border = style.border;
+
+                        // The background color tints images, it is white unless set otherwise
+                        let default_background = match image {
+                            Some(_) => Color::WHITE,
+                            None => Color::NONE,
+                        };
+                        let background_color = background_color
+                            .map(|color| color.0)
+                            .unwrap_or(default_background);
+                        let border_color = border_color.map(|color| color.0).unwrap_or(Color::NONE);
+                        let background_color = faded(background_color, opacity);
+                        let border_color = faded(border_color, opacity);
+
+                        let mut preview_builder = if let Some(image) = image {
+                            builder.spawn((
+                                ImageBundle {
+                                    style: preview_style,
+                                    image: image.clone(),
+                                    background_color: background_color.into(),
+                                    focus_policy: node_bundle.focus_policy,
+                                    z_index: node_bundle.z_index,
+                                    ..default()
+                                },
+                                BorderColor(border_color),
+                            ))
+                        } else {
+                            builder.spawn(NodeBundle {
+                                style: preview_style,
+                                background_color: background_color.into(),
+                                border_color: border_color.into(),
+                                ..node_bundle
+                            })
+                        };
+
+                        // Shaped nodes are drawn by their `NodeShape`, not their background
+                        if let Some(node_shape) = node_shape {
+                            preview_builder.insert(NodeShape {
+                                color: faded(node_shape.color, opacity),
+                                border_color: faded(node_shape.border_color, opacity),
+                                shadow_color: faded(node_shape.shadow_color, opacity),
+                                ..*node_shape
+                            });
+                        }
+
+                        preview_builder
+                    }
+                    DragPreviewContent::Builder(spawn_children) => {
+                        let mut preview_builder = builder.spawn(NodeBundle {
+                            style: preview_style,
+                            ..node_bundle
+                        });
+                        spawn_children(&mut preview_builder);
+
+                        preview_builder
+                    }
+                };
+
+                let preview = preview_builder
+                    .insert((
+                        Name::new(format!("Drag Preview of [{:?}]", entity)),
+                        DragPreviewNode { source: entity },
+                    ))
+                    .id();
+
+                drag_preview.preview = preview.into();
+            }
+            DragState::Dragging => {
+                let (Some(preview), Some(position)) = (drag_preview.preview, draggable.position)
+                else {
+                    continue;
+                };
+                let Ok(mut preview_style) = q_preview_styles.get_mut(preview) else {
+                    continue;
+                };
+
+                let preview_position = drag_preview.position(position);
+                preview_style.left = Val::Px(preview_position.x);
+                preview_style.top = Val::Px(preview_position.y);
+            }
+            DragState::DragEnd | DragState::DragCanceled | DragState::Inactive => {
+                let Some(preview) = drag_preview.preview else {
+                    continue;
+                };
+
+                commands.entity(preview).despawn_recursive();
+                drag_preview.preview = None;
+            }
+            DragState::MaybeDragged => (),
+        }
+    }
+}
+
+fn faded(color: Color, opacity: f32) -> Color {
+    color.with_a(color.a() * opacity)
+}
+
+fn delete_orphaned_drag_preview_nodes(
+    q_preview_nodes: Query<(Entity, &DragPreviewNode)>,
+    mut commands: Commands,
+) {
+    for (entity, preview_node) in &q_preview_nodes {
+        if commands.get_entity(preview_node.source).is_none() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
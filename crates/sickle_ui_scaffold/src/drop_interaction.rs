@@ -1,17 +1,25 @@
+use std::{any::TypeId, sync::Arc};
+
 use bevy::{prelude::*, ui::RelativeCursorPosition};
 
-use crate::drag_interaction::{DragState, Draggable, DraggableUpdate};
+use crate::{
+    drag_interaction::{DragState, Draggable, DraggableUpdate},
+    theme::pseudo_state::PseudoState,
+    ui_commands::ManagePseudoStateExt,
+};
 
 pub struct DropInteractionPlugin;
 
 impl Plugin for DropInteractionPlugin {
     fn build(&self, app: &mut App) {
         app.configure_sets(Update, DroppableUpdate.after(DraggableUpdate))
+            .add_event::<Dropped>()
             .add_systems(
                 Update,
                 (
                     update_drop_zone_single_frame_state,
                     update_drop_zones.run_if(should_update_drop_zones),
+                    update_drop_zone_pseudo_states,
                 )
                     .chain()
                     .in_set(DroppableUpdate),
@@ -31,6 +39,7 @@ fn update_drop_zone_single_frame_state(mut q_drop_zones: Query<&mut DropZone, Ch
             drop_zone.drop_phase = DropPhase::Inactive;
             drop_zone.incoming_droppable = None;
             drop_zone.position = None;
+            drop_zone.accepted = false;
         } else if drop_zone.drop_phase == DropPhase::DroppableEntered {
             drop_zone.drop_phase = DropPhase::DroppableHover
         }
@@ -46,9 +55,13 @@ fn should_update_drop_zones(
 }
 
 fn update_drop_zones(
-    q_droppables: Query<(Entity, &Draggable), (With<Droppable>, Changed<Draggable>)>,
+    q_droppables: Query<
+        (Entity, &Draggable, Option<&DragPayload>),
+        (With<Droppable>, Changed<Draggable>),
+    >,
     q_drop_zone_data: Query<(Entity, &Interaction, &Node, &RelativeCursorPosition), With<DropZone>>,
     mut q_drop_zones: Query<(Entity, &mut DropZone)>,
+    mut dropped_events: EventWriter<Dropped>,
 ) {
     // Run condition makes sure we are dragging a droppable.
     // We have no information if the interaction is from the same source,
@@ -72,19 +85,28 @@ fn update_drop_zones(
 
         // Take the first droppable that is moving
         // ui_focus, flux and drag interactions only track the main pointer interaction
-        let (droppable_entity, draggable) = q_droppables
+        let (droppable_entity, draggable, payload) = q_droppables
             .iter()
-            .find(|(_, draggable)| {
+            .find(|(_, draggable, _)| {
                 draggable.state != DragState::Inactive && draggable.state != DragState::MaybeDragged
             })
             .unwrap();
 
         // See update_drop_zone_single_frame_state which executes just before this system
         if drop_zone.drop_phase == DropPhase::Inactive {
-            drop_zone.drop_phase = DropPhase::DroppableEntered
-        } else if draggable.state == DragState::DragEnd {
+            drop_zone.drop_phase = DropPhase::DroppableEntered;
+            drop_zone.accepted = drop_zone.accepts(payload);
+        } else if draggable.state == DragState::DragEnd && drop_zone.accepted {
             drop_zone.drop_phase = DropPhase::Dropped;
-        } else if draggable.state == DragState::DragCanceled {
+            dropped_events.send(Dropped {
+                drop_zone: *top_hovered,
+                droppable: droppable_entity,
+                position: draggable.position,
+                payload: payload.map(|payload| payload.0.clone()),
+            });
+        } else if draggable.state == DragState::DragEnd
+            || draggable.state == DragState::DragCanceled
+        {
             drop_zone.drop_phase = DropPhase::DropCanceled;
         }
 
@@ -111,6 +133,7 @@ fn update_drop_zones(
                 drop_zone.drop_phase = DropPhase::DroppableLeft;
                 drop_zone.incoming_droppable = None;
                 drop_zone.position = None;
+                drop_zone.accepted = false;
             }
         }
     } else {
@@ -121,11 +144,37 @@ fn update_drop_zones(
                 drop_zone.drop_phase = DropPhase::DroppableLeft;
                 drop_zone.incoming_droppable = None;
                 drop_zone.position = None;
+                drop_zone.accepted = false;
             }
         }
     }
 }
 
+fn update_drop_zone_pseudo_states(
+    q_drop_zones: Query<(Entity, &DropZone), Changed<DropZone>>,
+    mut commands: Commands,
+) {
+    for (entity, drop_zone) in &q_drop_zones {
+        let hovered = drop_zone.drop_phase == DropPhase::DroppableEntered
+            || drop_zone.drop_phase == DropPhase::DroppableHover;
+
+        let mut entity_commands = commands.entity(entity);
+        if hovered && drop_zone.accepted {
+            entity_commands
+                .add_pseudo_state(PseudoState::DropAccepted)
+                .remove_pseudo_state(PseudoState::DropRejected);
+        } else if hovered {
+            entity_commands
+                .add_pseudo_state(PseudoState::DropRejected)
+                .remove_pseudo_state(PseudoState::DropAccepted);
+        } else {
+            entity_commands
+                .remove_pseudo_state(PseudoState::DropAccepted)
+                .remove_pseudo_state(PseudoState::DropRejected);
+        }
+    }
+}
+
 #[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Reflect)]
 #[reflect]
 pub enum DropPhase {
@@ -138,17 +187,90 @@ pub enum DropPhase {
     DropCanceled,
 }
 
+/// Sent when a `Droppable` is dropped on a `DropZone` that accepts it
+#[derive(Event, Debug)]
+pub struct Dropped {
+    pub drop_zone: Entity,
+    pub droppable: Entity,
+    pub position: Option<Vec2>,
+    /// The `DragPayload` value of the droppable, shared with it
+    pub payload: Option<Arc<dyn Reflect>>,
+}
+
+impl Dropped {
+    pub fn payload<T: Reflect>(&self) -> Option<&T> {
+        self.payload
+            .as_ref()
+            .and_then(|payload| payload.downcast_ref::<T>())
+    }
+}
+
 #[derive(Component, Debug, Default, Reflect)]
 pub struct Droppable;
 
+/// Typed value carried by a `Droppable`, checked against the types its `DropZone`s accept.
+///
+/// Can be inserted up front, or by a system reacting to `DragState::DragStart` before
+/// `DroppableUpdate`.
+#[derive(Component, Debug)]
+pub struct DragPayload(Arc<dyn Reflect>);
+
+impl DragPayload {
+    pub fn new(value: impl Reflect) -> Self {
+        Self(Arc::new(value))
+    }
+
+    pub fn value(&self) -> &dyn Reflect {
+        self.0.as_ref()
+    }
+
+    pub fn get<T: Reflect>(&self) -> Option<&T> {
+        self.0.downcast_ref::<T>()
+    }
+
+    pub fn is<T: Reflect>(&self) -> bool {
+        self.0.is::<T>()
+    }
+
+    fn type_id(&self) -> TypeId {
+        self.0.as_any().type_id()
+    }
+}
+
+/// Tracks `Droppable`s dragged over the entity.
+///
+/// Accepts any droppable by default. Once a payload type is added with `accept`, only droppables
+/// carrying a `DragPayload` of one of the accepted types can be dropped. While hovered, the zone
+/// has either `PseudoState::DropAccepted` or `PseudoState::DropRejected`.
 #[derive(Component, Debug, Default, Reflect)]
 pub struct DropZone {
     drop_phase: DropPhase,
     incoming_droppable: Option<Entity>,
     position: Option<Vec2>,
+    accepted: bool,
+    #[reflect(ignore)]
+    accepted_types: Vec<TypeId>,
 }
 
 impl DropZone {
+    pub fn accept<T: Reflect>(mut self) -> Self {
+        self.accepted_types.push(TypeId::of::<T>());
+        self
+    }
+
+    pub fn accepts(&self, payload: Option<&DragPayload>) -> bool {
+        if self.accepted_types.is_empty() {
+            return true;
+        }
+
+        payload.is_some_and(|payload| self.accepted_types.contains(&payload.type_id()))
+    }
+
+    /// Whether the incoming droppable can be dropped here
+    pub fn accepted(&self) -> bool {
+        self.accepted
+    }
+
     pub fn drop_phase(&self) -> DropPhase {
         self.drop_phase
     }
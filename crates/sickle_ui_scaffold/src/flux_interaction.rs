@@ -3,6 +3,12 @@ use std::{cmp::Ordering, ops::Add, time::Duration};
 use bevy::{prelude::*, time::Stopwatch, utils::HashMap};
 use bevy_reflect::Reflect;
 
+use crate::{
+    drag_interaction::{DragState, Draggable},
+    long_press_interaction::{LongPressState, LongPressable},
+    ui_time::UiTime,
+};
+
 pub struct FluxInteractionPlugin;
 
 impl Plugin for FluxInteractionPlugin {
@@ -14,6 +20,7 @@ impl Plugin for FluxInteractionPlugin {
                 (
                     tick_flux_interaction_stopwatch,
                     update_flux_interaction,
+                    update_click_count,
                     reset_flux_interaction_stopwatch_on_change,
                     update_prev_interaction,
                 )
@@ -26,12 +33,15 @@ impl Plugin for FluxInteractionPlugin {
 #[derive(Resource, Clone, Debug, Reflect)]
 pub struct FluxInteractionConfig {
     pub max_interaction_duration: f32,
+    /// Maximum seconds (in real time) between two releases to count them as consecutive clicks
+    pub click_interval: f32,
 }
 
 impl Default for FluxInteractionConfig {
     fn default() -> Self {
         Self {
             max_interaction_duration: 1.,
+            click_interval: 0.4,
         }
     }
 }
@@ -44,6 +54,7 @@ pub struct TrackedInteraction {
     pub interaction: FluxInteraction,
     pub prev_interaction: PrevInteraction,
     pub stopwatch: FluxInteractionStopwatch,
+    pub click_count: ClickCount,
 }
 
 #[derive(Component, Clone, Copy, Debug, Default, Eq, PartialEq, Reflect)]
@@ -86,6 +97,32 @@ impl FluxInteraction {
     }
 }
 
+/// Number of consecutive clicks, updated when `FluxInteraction` changes to `Released`.
+///
+/// Releases closer than `FluxInteractionConfig::click_interval` to each other count as consecutive.
+/// Ending a drag resets the count.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct ClickCount {
+    count: u32,
+    last_click: Option<Duration>,
+}
+
+impl ClickCount {
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+    pub fn is_single(&self) -> bool {
+        self.count == 1
+    }
+    pub fn is_double(&self) -> bool {
+        self.count == 2
+    }
+    pub fn is_triple(&self) -> bool {
+        self.count == 3
+    }
+}
+
 #[derive(Component, Clone, Debug, Default)]
 #[component(storage = "SparseSet")]
 pub struct FluxInteractionStopwatch(pub Stopwatch);
@@ -192,7 +229,7 @@ pub enum PrevInteraction {
 
 fn tick_flux_interaction_stopwatch(
     config: Res<FluxInteractionConfig>,
-    time: Res<Time<Real>>,
+    time: Res<UiTime>,
     mut q_stopwatches: Query<(
         Entity,
         &mut FluxInteractionStopwatch,
@@ -222,12 +259,21 @@ fn tick_flux_interaction_stopwatch(
 }
 
 fn update_flux_interaction(
+    r_touches: Res<Touches>,
+    ui_scale: Res<UiScale>,
     mut q_interaction: Query<
-        (&PrevInteraction, &Interaction, &mut FluxInteraction),
+        (
+            &PrevInteraction,
+            &Interaction,
+            &mut FluxInteraction,
+            Option<&Node>,
+            Option<&GlobalTransform>,
+            Option<&LongPressable>,
+        ),
         Changed<Interaction>,
     >,
 ) {
-    for (prev, curr, mut flux) in &mut q_interaction {
+    for (prev, curr, mut flux, node, transform, long_press) in &mut q_interaction {
         if *flux == FluxInteraction::Disabled {
             continue;
         }
@@ -241,13 +287,69 @@ fn update_flux_interaction(
         } else if *prev == PrevInteraction::Hovered && *curr == Interaction::None {
             *flux = FluxInteraction::PointerLeave;
         } else if *prev == PrevInteraction::Pressed && *curr == Interaction::None {
-            *flux = FluxInteraction::PressCanceled;
+            // Lifting a touch leaves no cursor to hover the node, check where it was released.
+            // A long press is not a click.
+            let long_pressed = long_press.is_some_and(|long_press| {
+                long_press.state == LongPressState::LongPressed
+                    || long_press.state == LongPressState::Holding
+            });
+            let released_over = match (node, transform) {
+                _ if long_pressed => false,
+                (Some(node), Some(transform)) => {
+                    let rect = node.logical_rect(transform);
+                    r_touches
+                        .iter_just_released()
+                        .any(|touch| rect.contains(touch.position() / ui_scale.0))
+                }
+                _ => false,
+            };
+
+            *flux = match released_over {
+                true => FluxInteraction::Released,
+                false => FluxInteraction::PressCanceled,
+            };
         } else if *prev == PrevInteraction::Pressed && *curr == Interaction::Hovered {
             *flux = FluxInteraction::Released;
         }
     }
 }
 
+fn update_click_count(
+    config: Res<FluxInteractionConfig>,
+    time: Res<Time<Real>>,
+    mut q_click_counts: Query<
+        (&FluxInteraction, &mut ClickCount, Option<&Draggable>),
+        Changed<FluxInteraction>,
+    >,
+) {
+    let now = time.elapsed();
+    for (interaction, mut click_count, draggable) in &mut q_click_counts {
+        if *interaction != FluxInteraction::Released {
+            continue;
+        }
+
+        // Draggable is updated after this system, a drag being released is still in progress
+        let dragged = draggable.is_some_and(|draggable| {
+            draggable.state == DragState::DragStart || draggable.state == DragState::Dragging
+        });
+        if dragged {
+            click_count.count = 0;
+            click_count.last_click = None;
+            continue;
+        }
+
+        let consecutive = click_count
+            .last_click
+            .is_some_and(|last_click| (now - last_click).as_secs_f32() <= config.click_interval);
+
+        click_count.count = match consecutive {
+            true => click_count.count + 1,
+            false => 1,
+        };
+        click_count.last_click = now.into();
+    }
+}
+
 fn reset_flux_interaction_stopwatch_on_change(
     mut q_stopwatch: Query<
         (Entity, Option<&mut FluxInteractionStopwatch>),
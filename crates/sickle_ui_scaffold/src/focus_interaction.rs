@@ -0,0 +1,272 @@
+use bevy::prelude::*;
+use bevy_reflect::Reflect;
+
+use crate::{
+    flux_interaction::{FluxInteraction, FluxInteractionUpdate},
+    theme::pseudo_state::PseudoState,
+    ui_commands::ManagePseudoStateExt,
+};
+
+pub struct FocusInteractionPlugin;
+
+impl Plugin for FocusInteractionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FocusManager>()
+            .configure_sets(Update, FocusableUpdate.after(FluxInteractionUpdate))
+            .add_systems(
+                Update,
+                (
+                    clear_removed_focus,
+                    update_focus_on_press,
+                    update_focus_on_tab,
+                    update_focused_pseudo_state,
+                )
+                    .chain()
+                    .in_set(FocusableUpdate),
+            );
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct FocusableUpdate;
+
+/// Marks an entity that can receive keyboard focus.
+///
+/// Focus moves with Tab / Shift+Tab in document order, or by pressing the entity (or any of
+/// its descendants that is not focusable itself). The focused entity gets `PseudoState::Focused`,
+/// unless it was focused by pointer.
+///
+/// Document order is depth first within a root node. Root nodes follow each other in drawing
+/// order, so roots with a higher `ZIndex` come later. The relative order of roots with the same
+/// `ZIndex` is unspecified, nest them under a common root to control it.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct Focusable {
+    /// Explicit position in the tab order.
+    ///
+    /// Entities with a positive index are visited first, in ascending order, followed by the
+    /// ones with no index (or zero) in document order. Entities with a negative index can only
+    /// be focused by pointer or programmatically.
+    pub tab_index: Option<i32>,
+}
+
+impl Focusable {
+    pub fn with_tab_index(tab_index: i32) -> Self {
+        Self {
+            tab_index: tab_index.into(),
+        }
+    }
+
+    pub fn in_tab_order(&self) -> bool {
+        match self.tab_index {
+            Some(index) => index >= 0,
+            None => true,
+        }
+    }
+}
+
+/// How the focused entity received focus
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Reflect)]
+pub enum FocusOrigin {
+    /// Keyboard, gamepad or a programmatic call
+    #[default]
+    Navigation,
+    /// Mouse or touch press
+    Pointer,
+}
+
+/// Tracks the entity holding keyboard focus.
+///
+/// Focus set by pointer is not visible: the entity does not get `PseudoState::Focused` and
+/// navigation actions do not target it, same as `:focus-visible` on the web. Tab still moves on
+/// from it.
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct FocusManager {
+    focused: Option<Entity>,
+    origin: FocusOrigin,
+    prev_visible: Option<Entity>,
+}
+
+impl FocusManager {
+    pub fn focused(&self) -> Option<Entity> {
+        self.focused
+    }
+
+    pub fn is_focused(&self, entity: Entity) -> bool {
+        self.focused == Some(entity)
+    }
+
+    pub fn origin(&self) -> FocusOrigin {
+        self.origin
+    }
+
+    /// The focused entity, if its focus was not set by pointer
+    pub fn focus_visible(&self) -> Option<Entity> {
+        match self.origin {
+            FocusOrigin::Navigation => self.focused,
+            FocusOrigin::Pointer => None,
+        }
+    }
+
+    /// Focuses the entity as if by keyboard or gamepad
+    pub fn focus(&mut self, entity: Entity) {
+        self.focus_with(entity, FocusOrigin::Navigation);
+    }
+
+    pub fn focus_with(&mut self, entity: Entity, origin: FocusOrigin) {
+        if self.focused != Some(entity) || self.origin != origin {
+            self.focused = entity.into();
+            self.origin = origin;
+        }
+    }
+
+    pub fn clear(&mut self) {
+        if self.focused.is_some() {
+            self.focused = None;
+        }
+    }
+}
+
+fn clear_removed_focus(
+    mut r_focus: ResMut<FocusManager>,
+    q_focusables: Query<Entity, With<Focusable>>,
+    mut q_removed_focusables: RemovedComponents<Focusable>,
+) {
+    let Some(focused) = r_focus.focused else {
+        q_removed_focusables.clear();
+        return;
+    };
+
+    let removed = q_removed_focusables.read().any(|entity| entity == focused);
+    if removed || !q_focusables.contains(focused) {
+        r_focus.clear();
+    }
+}
+
+fn update_focus_on_press(
+    r_mouse: Res<ButtonInput<MouseButton>>,
+    r_touches: Res<Touches>,
+    q_pressed: Query<(Entity, &FluxInteraction), Changed<FluxInteraction>>,
+    q_focusables: Query<&Focusable>,
+    q_parents: Query<&Parent>,
+    mut r_focus: ResMut<FocusManager>,
+) {
+    // Confirm presses the focused entity without a pointer, that keeps the focus as is
+    let pressed_anywhere =
+        r_mouse.any_just_pressed([MouseButton::Left, MouseButton::Middle, MouseButton::Right])
+            || r_touches.any_just_pressed();
+    if !pressed_anywhere {
+        return;
+    }
+
+    let mut pressed_focusable: Option<Entity> = None;
+    for (entity, interaction) in &q_pressed {
+        if *interaction != FluxInteraction::Pressed {
+            continue;
+        }
+
+        let focusable = std::iter::once(entity)
+            .chain(q_parents.iter_ancestors(entity))
+            .find(|entity| q_focusables.contains(*entity));
+        if focusable.is_some() {
+            pressed_focusable = focusable;
+        }
+    }
+
+    if let Some(focusable) = pressed_focusable {
+        r_focus.focus_with(focusable, FocusOrigin::Pointer);
+    } else if r_focus.focused.is_some() {
+        r_focus.clear();
+    }
+}
+
+fn update_focus_on_tab(
+    r_keys: Res<ButtonInput<KeyCode>>,
+    q_roots: Query<(Entity, &Node), Without<Parent>>,
+    q_children: Query<&Children>,
+    q_focusables: Query<(&Focusable, &InheritedVisibility, Option<&FluxInteraction>)>,
+    mut r_focus: ResMut<FocusManager>,
+) {
+    if !r_keys.just_pressed(KeyCode::Tab) {
+        return;
+    }
+
+    // Roots in drawing order, which follows their `ZIndex`
+    let mut roots: Vec<(Entity, &Node)> = q_roots.iter().collect();
+    roots.sort_by_key(|(_, node)| node.stack_index());
+
+    let mut indexed: Vec<(i32, Entity)> = Vec::new();
+    let mut document_ordered: Vec<Entity> = Vec::new();
+    for (root, _) in roots {
+        // Depth first, children in order
+        let mut stack = vec![root];
+        while let Some(entity) = stack.pop() {
+            if let Ok(children) = q_children.get(entity) {
+                stack.extend(children.iter().rev());
+            }
+
+            let Ok((focusable, visibility, interaction)) = q_focusables.get(entity) else {
+                continue;
+            };
+
+            if !visibility.get()
+                || !focusable.in_tab_order()
+                || interaction.is_some_and(|interaction| interaction.is_disabled())
+            {
+                continue;
+            }
+
+            match focusable.tab_index {
+                Some(index) if index > 0 => indexed.push((index, entity)),
+                _ => document_ordered.push(entity),
+            }
+        }
+    }
+
+    // Stable sort keeps document order among equal indices
+    indexed.sort_by_key(|(index, _)| *index);
+    let tab_order: Vec<Entity> = indexed
+        .into_iter()
+        .map(|(_, entity)| entity)
+        .chain(document_ordered)
+        .collect();
+
+    if tab_order.is_empty() {
+        return;
+    }
+
+    let backwards = r_keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let current = r_focus
+        .focused
+        .and_then(|focused| tab_order.iter().position(|entity| *entity == focused));
+    let next = match (current, backwards) {
+        (Some(index), false) => (index + 1) % tab_order.len(),
+        (Some(index), true) => (index + tab_order.len() - 1) % tab_order.len(),
+        (None, false) => 0,
+        (None, true) => tab_order.len() - 1,
+    };
+
+    r_focus.focus(tab_order[next]);
+}
+
+fn update_focused_pseudo_state(mut r_focus: ResMut<FocusManager>, mut commands: Commands) {
+    let visible = r_focus.focus_visible();
+    if !r_focus.is_changed() || visible == r_focus.prev_visible {
+        return;
+    }
+
+    if let Some(prev_visible) = r_focus.prev_visible {
+        if let Some(mut entity_commands) = commands.get_entity(prev_visible) {
+            entity_commands.remove_pseudo_state(PseudoState::Focused);
+        }
+    }
+
+    if let Some(visible) = visible {
+        if let Some(mut entity_commands) = commands.get_entity(visible) {
+            entity_commands.add_pseudo_state(PseudoState::Focused);
+        }
+    }
+
+    r_focus.bypass_change_detection().prev_visible = visible;
+}
@@ -0,0 +1,277 @@
+use bevy::{ecs::system::SystemParam, prelude::*, ui::UiSystem, utils::HashSet};
+
+use crate::theme::{dynamic_style::DynamicStylePostUpdate, typography::SizedFont};
+
+pub struct InheritedTextStylePlugin;
+
+impl Plugin for InheritedTextStylePlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(
+            PostUpdate,
+            InheritedTextStyleUpdate
+                .after(DynamicStylePostUpdate)
+                .before(UiSystem::Layout),
+        )
+        .register_type::<InheritedTextStyle>()
+        .register_type::<ExplicitTextStyle>()
+        .register_type::<TextStyleFallback>()
+        .add_systems(
+            PostUpdate,
+            update_inherited_text_styles.in_set(InheritedTextStyleUpdate),
+        );
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct InheritedTextStyleUpdate;
+
+/// Text properties a node passes down to the `Text` of its descendants.
+///
+/// Each property is resolved from the nearest ancestor (or the text node itself) that sets it.
+/// Text nodes that are styled explicitly, via the `font`, `font_size`, `sized_font` or
+/// `font_color` attributes, keep their own values (see [`ExplicitTextStyle`]). When a property
+/// is no longer inherited, text nodes get back the value they had before inheriting it.
+///
+/// Usually set via the `inherited_*` style attributes.
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct InheritedTextStyle {
+    pub font: Option<String>,
+    pub font_size: Option<f32>,
+    pub color: Option<Color>,
+}
+
+impl InheritedTextStyle {
+    pub fn sized_font(font: SizedFont) -> Self {
+        Self {
+            font: font.font.into(),
+            font_size: font.size.into(),
+            ..default()
+        }
+    }
+
+    pub fn with_color(self, color: Color) -> Self {
+        Self {
+            color: color.into(),
+            ..self
+        }
+    }
+}
+
+/// Marks the text properties of a node that were set explicitly and must not be inherited.
+///
+/// Inserted by the text related style commands. Icons rendered from font codepoints are
+/// always explicit. Remove the component or unset a flag to inherit the property again.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct ExplicitTextStyle {
+    pub font: bool,
+    pub font_size: bool,
+    pub color: bool,
+}
+
+impl ExplicitTextStyle {
+    pub const ALL: ExplicitTextStyle = ExplicitTextStyle {
+        font: true,
+        font_size: true,
+        color: true,
+    };
+
+    pub(crate) fn mark(entity: Entity, world: &mut World, update: impl Fn(&mut Self)) {
+        if let Some(mut explicit) = world.get_mut::<ExplicitTextStyle>(entity) {
+            let mut new_explicit = *explicit;
+            update(&mut new_explicit);
+            if *explicit != new_explicit {
+                *explicit = new_explicit;
+            }
+        } else {
+            let mut explicit = ExplicitTextStyle::default();
+            update(&mut explicit);
+            world.entity_mut(entity).insert(explicit);
+        }
+    }
+}
+
+/// The values a text node had before it started inheriting, restored once it stops
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct TextStyleFallback {
+    font: Option<Handle<Font>>,
+    font_size: Option<f32>,
+    color: Option<Color>,
+}
+
+type TextQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static mut Text,
+        Option<&'static ExplicitTextStyle>,
+        Option<&'static mut TextStyleFallback>,
+    ),
+>;
+
+#[derive(SystemParam)]
+struct InheritedTextStyleChanges<'w, 's> {
+    q_changed_sources: Query<'w, 's, Entity, Changed<InheritedTextStyle>>,
+    q_removed_sources: RemovedComponents<'w, 's, InheritedTextStyle>,
+    q_reparented: Query<'w, 's, Entity, Changed<Parent>>,
+    q_changed_explicit: Query<'w, 's, Entity, (With<Text>, Changed<ExplicitTextStyle>)>,
+    q_removed_explicit: RemovedComponents<'w, 's, ExplicitTextStyle>,
+}
+
+fn update_inherited_text_styles(
+    mut changes: InheritedTextStyleChanges,
+    q_children: Query<&Children>,
+    q_parent: Query<&Parent>,
+    q_sources: Query<&InheritedTextStyle>,
+    q_is_text: Query<(), With<Text>>,
+    // The changed text is read separately so the text itself is not iterated mutably every frame
+    mut q_text: ParamSet<(Query<Entity, Changed<Text>>, TextQuery)>,
+    asset_server: Res<AssetServer>,
+    mut commands: Commands,
+) {
+    if q_sources.is_empty() && changes.q_removed_sources.is_empty() {
+        return;
+    }
+
+    let mut targets: HashSet<Entity> = q_text
+        .p0()
+        .iter()
+        .chain(changes.q_changed_explicit.iter())
+        .chain(changes.q_removed_explicit.read())
+        .filter(|entity| q_is_text.contains(*entity))
+        .collect();
+
+    // Sources and moved subtrees affect the text of every descendant
+    for root in changes
+        .q_changed_sources
+        .iter()
+        .chain(changes.q_removed_sources.read())
+        .chain(changes.q_reparented.iter())
+    {
+        if q_is_text.contains(root) {
+            targets.insert(root);
+        }
+        for descendant in q_children.iter_descendants(root) {
+            if q_is_text.contains(descendant) {
+                targets.insert(descendant);
+            }
+        }
+    }
+
+    let mut q_text = q_text.p1();
+    for entity in targets.iter() {
+        let resolved = resolve_text_style(*entity, &q_parent, &q_sources);
+
+        let Ok((mut text, explicit, current_fallback)) = q_text.get_mut(*entity) else {
+            continue;
+        };
+        let Some(own_style) = text.sections.first().map(|section| section.style.clone()) else {
+            continue;
+        };
+        let explicit = explicit.copied().unwrap_or_default();
+        let mut fallback = current_fallback.as_deref().cloned().unwrap_or_default();
+
+        // Explicit values are never restored, inherited ones fall back to the original value
+        let font = match (explicit.font, &resolved.font) {
+            (true, _) => {
+                fallback.font = None;
+                None
+            }
+            (false, Some(font)) => {
+                fallback.font.get_or_insert(own_style.font);
+                Some(asset_server.load::<Font>(font.clone()))
+            }
+            (false, None) => fallback.font.take(),
+        };
+        let font_size = match (explicit.font_size, resolved.font_size) {
+            (true, _) => {
+                fallback.font_size = None;
+                None
+            }
+            (false, Some(font_size)) => {
+                fallback.font_size.get_or_insert(own_style.font_size);
+                Some(font_size)
+            }
+            (false, None) => fallback.font_size.take(),
+        };
+        let color = match (explicit.color, resolved.color) {
+            (true, _) => {
+                fallback.color = None;
+                None
+            }
+            (false, Some(color)) => {
+                fallback.color.get_or_insert(own_style.color);
+                Some(color)
+            }
+            (false, None) => fallback.color.take(),
+        };
+
+        match current_fallback {
+            Some(mut current_fallback) => {
+                if *current_fallback != fallback {
+                    *current_fallback = fallback;
+                }
+            }
+            None => {
+                if fallback != TextStyleFallback::default() {
+                    commands.entity(*entity).insert(fallback);
+                }
+            }
+        }
+
+        let needs_update = text.sections.iter().any(|section| {
+            font.as_ref()
+                .is_some_and(|font| section.style.font != *font)
+                || font_size.is_some_and(|size| section.style.font_size != size)
+                || color.is_some_and(|color| section.style.color != color)
+        });
+        if !needs_update {
+            continue;
+        }
+
+        for section in text.sections.iter_mut() {
+            if let Some(font) = &font {
+                section.style.font = font.clone();
+            }
+            if let Some(font_size) = font_size {
+                section.style.font_size = font_size;
+            }
+            if let Some(color) = color {
+                section.style.color = color;
+            }
+        }
+    }
+}
+
+fn resolve_text_style(
+    entity: Entity,
+    q_parent: &Query<&Parent>,
+    q_sources: &Query<&InheritedTextStyle>,
+) -> InheritedTextStyle {
+    let mut resolved = InheritedTextStyle::default();
+    let mut current = Some(entity);
+
+    while let Some(node) = current {
+        if let Ok(source) = q_sources.get(node) {
+            if resolved.font.is_none() {
+                resolved.font = source.font.clone();
+            }
+            if resolved.font_size.is_none() {
+                resolved.font_size = source.font_size;
+            }
+            if resolved.color.is_none() {
+                resolved.color = source.color;
+            }
+
+            if resolved.font.is_some() && resolved.font_size.is_some() && resolved.color.is_some() {
+                break;
+            }
+        }
+
+        current = q_parent.get(node).ok().map(|parent| parent.get());
+    }
+
+    resolved
+}
@@ -0,0 +1,173 @@
+use bevy::{prelude::*, ui::UiSystem};
+
+use sickle_math::ease::{Ease, ValueEasing};
+
+use crate::{
+    node_shape::NodeShapeBackgroundNode, ui_time::UiTime, ui_transform::UiTransformUpdate,
+};
+
+pub struct LayoutAnimationPlugin;
+
+impl Plugin for LayoutAnimationPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(
+            PostUpdate,
+            LayoutAnimationUpdate
+                .after(UiSystem::Layout)
+                .before(UiTransformUpdate),
+        )
+        .register_type::<AnimateLayout>()
+        .add_systems(
+            PostUpdate,
+            (clear_layout_animation_state, update_layout_animation_state)
+                .chain()
+                .in_set(LayoutAnimationUpdate),
+        );
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct LayoutAnimationUpdate;
+
+/// Animates the children of a node from their previous layout position to the new one
+/// when they are reordered, or when siblings are inserted or removed.
+///
+/// The offset is applied to the `Transform` of the children after layout, so it does not
+/// affect the flex layout itself. Absolutely positioned children are tracked, but not animated.
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+pub struct AnimateLayout {
+    pub duration: f32,
+    pub easing: Ease,
+}
+
+impl Default for AnimateLayout {
+    fn default() -> Self {
+        Self {
+            duration: 0.2,
+            easing: Ease::OutCubic,
+        }
+    }
+}
+
+impl AnimateLayout {
+    pub fn new(duration: f32, easing: Ease) -> Self {
+        Self { duration, easing }
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct LayoutAnimationState {
+    parent: Option<Entity>,
+    last_position: Vec2,
+    from_offset: Vec2,
+    elapsed: f32,
+    animating: bool,
+}
+
+impl LayoutAnimationState {
+    pub fn is_animating(&self) -> bool {
+        self.animating
+    }
+
+    fn current_offset(&self, config: &AnimateLayout) -> Vec2 {
+        if !self.animating || config.duration <= 0. {
+            return Vec2::ZERO;
+        }
+
+        let progress = (self.elapsed / config.duration)
+            .clamp(0., 1.)
+            .ease(config.easing);
+
+        self.from_offset * (1. - progress)
+    }
+}
+
+fn clear_layout_animation_state(
+    mut q_removed: RemovedComponents<AnimateLayout>,
+    q_children: Query<&Children>,
+    q_state: Query<Entity, With<LayoutAnimationState>>,
+    mut commands: Commands,
+) {
+    for entity in q_removed.read() {
+        let Ok(children) = q_children.get(entity) else {
+            continue;
+        };
+
+        for child in children.iter() {
+            if q_state.contains(*child) {
+                commands.entity(*child).remove::<LayoutAnimationState>();
+            }
+        }
+    }
+}
+
+fn update_layout_animation_state(
+    time: Res<UiTime>,
+    q_containers: Query<(Entity, &AnimateLayout, &Node, &Children)>,
+    mut q_nodes: Query<
+        (
+            &Node,
+            &Style,
+            &mut Transform,
+            Option<&mut LayoutAnimationState>,
+        ),
+        Without<NodeShapeBackgroundNode>,
+    >,
+    mut commands: Commands,
+) {
+    for (container, config, container_node, children) in &q_containers {
+        let parent_half_size = container_node.size() / 2.;
+
+        for child in children.iter() {
+            let Ok((node, style, mut transform, state)) = q_nodes.get_mut(*child) else {
+                continue;
+            };
+
+            // The layout has just written the new position into the translation. It is relative
+            // to the parent's center and points at the node's own center, so resizing either of
+            // them would shift it. The top-left relative to the parent's top-left only changes
+            // when the node actually moves in the layout.
+            let layout_position =
+                transform.translation.truncate() - node.size() / 2. + parent_half_size;
+
+            let Some(mut state) = state else {
+                commands.entity(*child).insert(LayoutAnimationState {
+                    parent: container.into(),
+                    last_position: layout_position,
+                    ..default()
+                });
+                continue;
+            };
+
+            if state.parent != Some(container) || style.position_type == PositionType::Absolute {
+                *state = LayoutAnimationState {
+                    parent: container.into(),
+                    last_position: layout_position,
+                    ..default()
+                };
+                continue;
+            }
+
+            if state.last_position != layout_position {
+                // Start from wherever the node is visible right now, even if mid-animation
+                let visible_offset = state.current_offset(config);
+                state.from_offset = state.last_position - layout_position + visible_offset;
+                state.last_position = layout_position;
+                state.elapsed = 0.;
+                state.animating = true;
+            } else if state.animating {
+                state.elapsed += time.delta_seconds();
+                if state.elapsed >= config.duration {
+                    state.animating = false;
+                }
+            }
+
+            let offset = state.current_offset(config);
+            if offset != Vec2::ZERO {
+                transform.translation += offset.extend(0.);
+            }
+        }
+    }
+}
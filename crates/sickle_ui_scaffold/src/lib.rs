@@ -2,12 +2,20 @@ pub mod animated_interaction;
 pub mod drag_interaction;
 pub mod drop_interaction;
 pub mod flux_interaction;
+pub mod focus_interaction;
+pub mod inherited_text_style;
 pub mod interactions;
+pub mod layout_animation;
+pub mod long_press_interaction;
+pub mod navigation_interaction;
+pub mod node_shape;
 pub mod scroll_interaction;
 pub mod theme;
 pub mod ui_builder;
 pub mod ui_commands;
 pub mod ui_style;
+pub mod ui_time;
+pub mod ui_transform;
 pub mod ui_utils;
 
 use bevy::reflect::Reflect;
@@ -15,24 +23,48 @@ use serde::{Deserialize, Serialize};
 
 pub mod prelude {
     pub use super::{
-        drag_interaction::{DragState, Draggable, DraggableUpdate},
-        drop_interaction::{DropPhase, DropZone, Droppable, DroppableUpdate},
+        drag_interaction::{
+            DragAxis, DragPreview, DragState, Draggable, DraggableConfig, DraggableUpdate,
+        },
+        drop_interaction::{DragPayload, DropPhase, DropZone, Droppable, DroppableUpdate, Dropped},
         flux_interaction::{
-            FluxInteraction, FluxInteractionStopwatch, FluxInteractionStopwatchLock,
+            ClickCount, FluxInteraction, FluxInteractionStopwatch, FluxInteractionStopwatchLock,
             FluxInteractionUpdate, TrackedInteraction,
         },
+        focus_interaction::{FocusManager, FocusOrigin, Focusable, FocusableUpdate},
+        inherited_text_style::{ExplicitTextStyle, InheritedTextStyle},
+        layout_animation::AnimateLayout,
+        long_press_interaction::{LongPressState, LongPressable, LongPressableUpdate},
+        navigation_interaction::{
+            BackNavigationHandler, NavigationAction, NavigationConfig, NavigationDirection,
+            NavigationEvent, NavigationNeighbors, NavigationTarget, NavigationUpdate,
+        },
+        node_shape::{CornerRadius, NodeShape, NodeShapeBackgroundNode},
         scroll_interaction::{ScrollAxis, Scrollable, ScrollableUpdate},
         theme::prelude::*,
         ui_builder::{UiBuilder, UiBuilderExt, UiContextRoot, UiRoot},
-        ui_commands::ManagePseudoStateExt,
+        ui_commands::{ManagePseudoStateExt, ManageStyleClassExt},
         ui_style::prelude::*,
+        ui_time::{UiTime, UiTimeMode},
+        ui_transform::{UiOffset, UiRotation, UiTransformOffset},
         ui_utils::UiUtils,
         CardinalDirection,
     };
 }
 
 #[derive(
-    Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd, Ord, Reflect, Serialize, Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Reflect,
+    Serialize,
+    Deserialize,
 )]
 pub enum CardinalDirection {
     #[default]
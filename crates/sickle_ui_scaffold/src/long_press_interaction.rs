@@ -0,0 +1,146 @@
+use bevy::prelude::*;
+use bevy_reflect::Reflect;
+
+use crate::flux_interaction::FluxInteractionUpdate;
+
+pub struct LongPressInteractionPlugin;
+
+impl Plugin for LongPressInteractionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LongPressConfig>()
+            .configure_sets(Update, LongPressableUpdate.after(FluxInteractionUpdate))
+            .add_systems(Update, update_long_press.in_set(LongPressableUpdate));
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct LongPressableUpdate;
+
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct LongPressConfig {
+    /// Seconds a touch must be held to count as a long press, measured in real time
+    pub duration: f32,
+    /// How far (in logical pixels) the touch may move before the long press is canceled
+    pub tolerance: f32,
+}
+
+impl Default for LongPressConfig {
+    fn default() -> Self {
+        Self {
+            duration: 0.5,
+            tolerance: 10.,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Reflect)]
+#[reflect]
+pub enum LongPressState {
+    #[default]
+    Inactive,
+    /// Touched, waiting for the duration to elapse
+    Pressing,
+    /// The long press happened this frame
+    LongPressed,
+    /// Still held after the long press
+    Holding,
+}
+
+/// Detects touches held on the entity, the touch equivalent of a right click.
+///
+/// Requires `Interaction`.
+#[derive(Component, Clone, Copy, Default, Debug, Reflect)]
+#[reflect(Component)]
+pub struct LongPressable {
+    pub state: LongPressState,
+    /// Touch position of the long press, in logical pixels
+    pub position: Option<Vec2>,
+    touch: Option<u64>,
+    elapsed: f32,
+}
+
+impl LongPressable {
+    pub fn long_pressed(&self) -> bool {
+        self.state == LongPressState::LongPressed
+    }
+
+    fn clear(&mut self) {
+        self.state = LongPressState::Inactive;
+        self.position = None;
+        self.touch = None;
+        self.elapsed = 0.;
+    }
+}
+
+fn update_long_press(
+    config: Res<LongPressConfig>,
+    time: Res<Time<Real>>,
+    r_touches: Res<Touches>,
+    ui_scale: Res<UiScale>,
+    mut q_long_pressables: Query<(
+        &mut LongPressable,
+        Ref<Interaction>,
+        &Node,
+        &GlobalTransform,
+    )>,
+) {
+    for (mut long_pressable, interaction, node, transform) in &mut q_long_pressables {
+        match long_pressable.state {
+            LongPressState::Inactive => {
+                if !interaction.is_changed() || *interaction != Interaction::Pressed {
+                    continue;
+                }
+
+                // With several fingers down, only the touch on this node counts
+                let node_rect = node.logical_rect(transform);
+                let Some(touch) = r_touches
+                    .iter_just_pressed()
+                    .find(|touch| node_rect.contains(touch.position() / ui_scale.0))
+                else {
+                    continue;
+                };
+
+                long_pressable.state = LongPressState::Pressing;
+                long_pressable.position = touch.start_position().into();
+                long_pressable.touch = touch.id().into();
+                long_pressable.elapsed = 0.;
+            }
+            LongPressState::Pressing => {
+                let (Some(id), Some(origin)) = (long_pressable.touch, long_pressable.position)
+                else {
+                    long_pressable.clear();
+                    continue;
+                };
+                let Some(touch) = r_touches.get_pressed(id) else {
+                    long_pressable.clear();
+                    continue;
+                };
+
+                if touch.position().distance(origin) > config.tolerance {
+                    long_pressable.clear();
+                    continue;
+                }
+
+                long_pressable.elapsed += time.delta().as_secs_f32();
+                if long_pressable.elapsed >= config.duration {
+                    long_pressable.state = LongPressState::LongPressed;
+                    long_pressable.position = touch.position().into();
+                }
+            }
+            LongPressState::LongPressed | LongPressState::Holding => {
+                let held = long_pressable
+                    .touch
+                    .is_some_and(|id| r_touches.get_pressed(id).is_some());
+
+                if held {
+                    if long_pressable.state == LongPressState::LongPressed {
+                        long_pressable.state = LongPressState::Holding;
+                    }
+                } else {
+                    long_pressable.clear();
+                }
+            }
+        }
+    }
+}
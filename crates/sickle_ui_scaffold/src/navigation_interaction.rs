@@ -0,0 +1,489 @@
+use bevy::{ecs::system::SystemParam, prelude::*};
+use bevy_reflect::Reflect;
+
+use crate::{
+    flux_interaction::{FluxInteraction, FluxInteractionUpdate},
+    focus_interaction::{FocusManager, Focusable, FocusableUpdate},
+};
+
+pub struct NavigationInteractionPlugin;
+
+impl Plugin for NavigationInteractionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<NavigationConfig>()
+            .add_event::<NavigationEvent>()
+            .configure_sets(
+                Update,
+                NavigationUpdate
+                    .after(FluxInteractionUpdate)
+                    .before(FocusableUpdate),
+            )
+            .add_systems(
+                Update,
+                (
+                    read_navigation_input,
+                    update_flux_interaction_on_confirm,
+                    update_focus_on_move.run_if(navigation_enabled),
+                )
+                    .chain()
+                    .in_set(NavigationUpdate)
+                    .run_if(navigation_enabled.or_else(keyboard_navigation_enabled)),
+            );
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct NavigationUpdate;
+
+fn navigation_enabled(config: Res<NavigationConfig>) -> bool {
+    config.enabled
+}
+
+fn keyboard_navigation_enabled(config: Res<NavigationConfig>) -> bool {
+    config.keyboard
+}
+
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct NavigationConfig {
+    /// Whether focus moves spatially between widgets and the gamepad is read. Disable it if the
+    /// arrow keys or gamepad buttons are needed elsewhere, e.g. while gameplay has the input.
+    pub enabled: bool,
+    /// Whether the keyboard drives the focused widget: confirm, back, and the arrow, Home and End
+    /// keys widgets handle themselves. Independent of `enabled`, so keyboard access keeps working
+    /// when spatial navigation is off.
+    pub keyboard: bool,
+    /// Minimum stick deflection that counts as a direction
+    pub stick_dead_zone: f32,
+    /// Seconds a direction must be held before it starts repeating
+    pub repeat_delay: f32,
+    /// Seconds between repeated moves while a direction is held
+    pub repeat_interval: f32,
+}
+
+impl Default for NavigationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            keyboard: true,
+            stick_dead_zone: 0.5,
+            repeat_delay: 0.5,
+            repeat_interval: 0.1,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Reflect)]
+pub enum NavigationDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl NavigationDirection {
+    fn vector(&self) -> Vec2 {
+        // UI space, Y grows downwards
+        match self {
+            NavigationDirection::Up => Vec2::NEG_Y,
+            NavigationDirection::Down => Vec2::Y,
+            NavigationDirection::Left => Vec2::NEG_X,
+            NavigationDirection::Right => Vec2::X,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Reflect)]
+pub enum NavigationAction {
+    Move(NavigationDirection),
+    ConfirmPressed,
+    ConfirmReleased,
+    Back,
+}
+
+/// Emitted for each navigation input, before focus is moved.
+///
+/// For `Move` and confirm actions the target is the entity focused when the input happened.
+/// For `Back` it is the closest [`BackNavigationHandler`] among the focused entity and its
+/// ancestors, or `None` if there isn't any.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct NavigationEvent {
+    pub target: Option<Entity>,
+    pub action: NavigationAction,
+}
+
+/// Where a directional move goes from an entity.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Reflect)]
+pub enum NavigationTarget {
+    /// Nearest focusable node in the direction
+    #[default]
+    Auto,
+    /// The given entity, if it can be focused
+    Entity(Entity),
+    /// Focus stays, the entity handles the move itself
+    Captured,
+}
+
+/// Explicit neighbors of a focusable entity, overriding the spatial search per direction.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct NavigationNeighbors {
+    pub up: NavigationTarget,
+    pub down: NavigationTarget,
+    pub left: NavigationTarget,
+    pub right: NavigationTarget,
+}
+
+impl NavigationNeighbors {
+    pub fn horizontal(target: NavigationTarget) -> Self {
+        Self {
+            left: target,
+            right: target,
+            ..default()
+        }
+    }
+
+    pub fn vertical(target: NavigationTarget) -> Self {
+        Self {
+            up: target,
+            down: target,
+            ..default()
+        }
+    }
+
+    pub fn get(&self, direction: NavigationDirection) -> NavigationTarget {
+        match direction {
+            NavigationDirection::Up => self.up,
+            NavigationDirection::Down => self.down,
+            NavigationDirection::Left => self.left,
+            NavigationDirection::Right => self.right,
+        }
+    }
+
+    pub fn set(&mut self, direction: NavigationDirection, target: NavigationTarget) {
+        match direction {
+            NavigationDirection::Up => self.up = target,
+            NavigationDirection::Down => self.down = target,
+            NavigationDirection::Left => self.left = target,
+            NavigationDirection::Right => self.right = target,
+        }
+    }
+}
+
+/// Marks an entity that handles the back action while it or one of its descendants is focused.
+///
+/// Widgets insert it while they have something to close, e.g. an open menu.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct BackNavigationHandler;
+
+#[derive(Default)]
+struct NavigationInputState {
+    direction: Option<NavigationDirection>,
+    repeat_timer: f32,
+    confirm: Option<Option<Entity>>,
+    back: bool,
+}
+
+#[derive(SystemParam)]
+struct NavigationInput<'w> {
+    time: Res<'w, Time<Real>>,
+    keys: Res<'w, ButtonInput<KeyCode>>,
+    gamepads: Res<'w, Gamepads>,
+    gamepad_buttons: Res<'w, ButtonInput<GamepadButton>>,
+    gamepad_axes: Res<'w, Axis<GamepadAxis>>,
+}
+
+impl NavigationInput<'_> {
+    fn gamepad_pressed(&self, button_type: GamepadButtonType) -> bool {
+        self.gamepads.iter().any(|gamepad| {
+            self.gamepad_buttons
+                .pressed(GamepadButton::new(gamepad, button_type))
+        })
+    }
+
+    /// Left stick of the gamepad with the largest deflection
+    fn stick(&self) -> Vec2 {
+        let mut stick = Vec2::ZERO;
+        for gamepad in self.gamepads.iter() {
+            let x = self
+                .gamepad_axes
+                .get(GamepadAxis::new(gamepad, GamepadAxisType::LeftStickX))
+                .unwrap_or_default();
+            let y = self
+                .gamepad_axes
+                .get(GamepadAxis::new(gamepad, GamepadAxisType::LeftStickY))
+                .unwrap_or_default();
+            let value = Vec2::new(x, y);
+            if value.length_squared() > stick.length_squared() {
+                stick = value;
+            }
+        }
+
+        stick
+    }
+
+    fn key_direction(&self) -> Option<NavigationDirection> {
+        if self.keys.pressed(KeyCode::ArrowUp) {
+            Some(NavigationDirection::Up)
+        } else if self.keys.pressed(KeyCode::ArrowDown) {
+            Some(NavigationDirection::Down)
+        } else if self.keys.pressed(KeyCode::ArrowLeft) {
+            Some(NavigationDirection::Left)
+        } else if self.keys.pressed(KeyCode::ArrowRight) {
+            Some(NavigationDirection::Right)
+        } else {
+            None
+        }
+    }
+
+    fn gamepad_direction(&self, stick_dead_zone: f32) -> Option<NavigationDirection> {
+        let stick = self.stick();
+
+        if self.gamepad_pressed(GamepadButtonType::DPadUp) {
+            Some(NavigationDirection::Up)
+        } else if self.gamepad_pressed(GamepadButtonType::DPadDown) {
+            Some(NavigationDirection::Down)
+        } else if self.gamepad_pressed(GamepadButtonType::DPadLeft) {
+            Some(NavigationDirection::Left)
+        } else if self.gamepad_pressed(GamepadButtonType::DPadRight) {
+            Some(NavigationDirection::Right)
+        } else if stick.length() < stick_dead_zone {
+            None
+        } else if stick.x.abs() > stick.y.abs() {
+            match stick.x > 0. {
+                true => Some(NavigationDirection::Right),
+                false => Some(NavigationDirection::Left),
+            }
+        } else {
+            // Stick Y grows upwards
+            match stick.y > 0. {
+                true => Some(NavigationDirection::Up),
+                false => Some(NavigationDirection::Down),
+            }
+        }
+    }
+
+    fn key_confirm(&self) -> bool {
+        self.keys
+            .any_pressed([KeyCode::Enter, KeyCode::NumpadEnter, KeyCode::Space])
+    }
+
+    fn key_back(&self) -> bool {
+        self.keys.pressed(KeyCode::Escape)
+    }
+}
+
+fn read_navigation_input(
+    config: Res<NavigationConfig>,
+    input: NavigationInput,
+    r_focus: Res<FocusManager>,
+    q_parents: Query<&Parent>,
+    q_back_handlers: Query<(), With<BackNavigationHandler>>,
+    mut state: Local<NavigationInputState>,
+    mut events: EventWriter<NavigationEvent>,
+) {
+    // Widgets focused by pointer are not driven by the keyboard or gamepad
+    let focused = r_focus.focus_visible();
+    let key_direction = match config.keyboard {
+        true => input.key_direction(),
+        false => None,
+    };
+    let gamepad_direction = match config.enabled {
+        true => input.gamepad_direction(config.stick_dead_zone),
+        false => None,
+    };
+    // Arrow keys only act on a focused widget, so they don't grab focus while e.g. a game uses
+    // them. The gamepad has no other way to reach the UI and focuses the first widget.
+    let direction = match focused {
+        Some(_) => key_direction.or(gamepad_direction),
+        None => gamepad_direction,
+    };
+
+    let mut moved = false;
+    if direction != state.direction {
+        state.direction = direction;
+        state.repeat_timer = config.repeat_delay;
+        moved = direction.is_some();
+    } else if direction.is_some() {
+        state.repeat_timer -= input.time.delta_seconds();
+        if state.repeat_timer <= 0. {
+            state.repeat_timer += config.repeat_interval;
+            moved = true;
+        }
+    }
+
+    if let (true, Some(direction)) = (moved, direction) {
+        events.send(NavigationEvent {
+            target: focused,
+            action: NavigationAction::Move(direction),
+        });
+    }
+
+    let confirm = (config.keyboard && input.key_confirm())
+        || (config.enabled && input.gamepad_pressed(GamepadButtonType::South));
+    match (confirm, state.confirm) {
+        (true, None) => {
+            state.confirm = Some(focused);
+            events.send(NavigationEvent {
+                target: focused,
+                action: NavigationAction::ConfirmPressed,
+            });
+        }
+        (false, Some(pressed)) => {
+            state.confirm = None;
+            events.send(NavigationEvent {
+                target: pressed,
+                action: NavigationAction::ConfirmReleased,
+            });
+        }
+        _ => (),
+    }
+
+    let back = (config.keyboard && input.key_back())
+        || (config.enabled && input.gamepad_pressed(GamepadButtonType::East));
+    if back && !state.back {
+        // Back closes popups opened by pointer too
+        let target = r_focus.focused().and_then(|focused| {
+            std::iter::once(focused)
+                .chain(q_parents.iter_ancestors(focused))
+                .find(|entity| q_back_handlers.contains(*entity))
+        });
+
+        events.send(NavigationEvent {
+            target,
+            action: NavigationAction::Back,
+        });
+    }
+    state.back = back;
+}
+
+fn update_flux_interaction_on_confirm(
+    mut events: EventReader<NavigationEvent>,
+    mut q_interactions: Query<&mut FluxInteraction>,
+) {
+    for event in events.read() {
+        let Some(target) = event.target else {
+            continue;
+        };
+        let Ok(mut interaction) = q_interactions.get_mut(target) else {
+            continue;
+        };
+
+        match event.action {
+            NavigationAction::ConfirmPressed if !interaction.is_disabled() => {
+                *interaction = FluxInteraction::Pressed;
+            }
+            NavigationAction::ConfirmReleased if interaction.is_pressed() => {
+                *interaction = FluxInteraction::Released;
+            }
+            _ => (),
+        }
+    }
+}
+
+type NavigationCandidate<'a> = (
+    Entity,
+    &'a Node,
+    &'a GlobalTransform,
+    &'a InheritedVisibility,
+    Option<&'a FluxInteraction>,
+);
+
+fn update_focus_on_move(
+    mut events: EventReader<NavigationEvent>,
+    q_focusables: Query<NavigationCandidate, With<Focusable>>,
+    q_neighbors: Query<&NavigationNeighbors>,
+    mut r_focus: ResMut<FocusManager>,
+) {
+    for event in events.read() {
+        let NavigationAction::Move(direction) = event.action else {
+            continue;
+        };
+
+        let candidates: Vec<(Entity, Rect)> = q_focusables
+            .iter()
+            .filter(|(_, _, _, visibility, interaction)| {
+                visibility.get()
+                    && !interaction.is_some_and(|interaction| interaction.is_disabled())
+            })
+            .map(|(entity, node, transform, _, _)| (entity, node.logical_rect(transform)))
+            .collect();
+
+        let Some(focused) = event.target else {
+            // Nothing visibly focused yet, start from the top left
+            let first = candidates.iter().min_by(|(_, a), (_, b)| {
+                (a.min.y, a.min.x)
+                    .partial_cmp(&(b.min.y, b.min.x))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+            if let Some((entity, _)) = first {
+                r_focus.focus(*entity);
+            }
+            continue;
+        };
+
+        if !r_focus.is_focused(focused) {
+            continue;
+        }
+
+        let neighbor = match q_neighbors.get(focused) {
+            Ok(neighbors) => neighbors.get(direction),
+            Err(_) => NavigationTarget::Auto,
+        };
+
+        match neighbor {
+            NavigationTarget::Auto => {
+                let Some((_, from)) = candidates.iter().find(|(entity, _)| *entity == focused)
+                else {
+                    continue;
+                };
+
+                if let Some(next) = nearest_in_direction(*from, direction, focused, &candidates) {
+                    r_focus.focus(next);
+                }
+            }
+            NavigationTarget::Entity(entity) => {
+                if candidates.iter().any(|(candidate, _)| *candidate == entity) {
+                    r_focus.focus(entity);
+                }
+            }
+            NavigationTarget::Captured => (),
+        }
+    }
+}
+
+/// Picks the candidate closest to `from` in `direction`.
+///
+/// Candidates must have their center past the center of `from`. The gap along the direction
+/// counts once, the misalignment across it twice, so nodes in line are preferred.
+fn nearest_in_direction(
+    from: Rect,
+    direction: NavigationDirection,
+    exclude: Entity,
+    candidates: &[(Entity, Rect)],
+) -> Option<Entity> {
+    let axis = direction.vector();
+    let cross = axis.perp().abs();
+
+    candidates
+        .iter()
+        .filter(|(entity, rect)| {
+            *entity != exclude && (rect.center() - from.center()).dot(axis) > 0.
+        })
+        .map(|(entity, rect)| {
+            let gap = axis_gap(from, *rect, axis.abs());
+            let misalignment = axis_gap(from, *rect, cross);
+            (*entity, gap + misalignment * 2.)
+        })
+        .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(entity, _)| entity)
+}
+
+/// Distance between the projections of two rects on an axis, zero if they overlap
+fn axis_gap(a: Rect, b: Rect, axis: Vec2) -> f32 {
+    let (a_min, a_max) = (a.min.dot(axis), a.max.dot(axis));
+    let (b_min, b_max) = (b.min.dot(axis), b.max.dot(axis));
+
+    (b_min - a_max).max(a_min - b_max).max(0.)
+}
@@ -0,0 +1,324 @@
+use bevy::{
+    asset::load_internal_asset,
+    prelude::*,
+    render::render_resource::{AsBindGroup, ShaderRef},
+    ui::{FocusPolicy, UiSystem},
+};
+
+use sickle_macros::Lerp;
+
+use crate::theme::dynamic_style::DynamicStylePostUpdate;
+
+pub const NODE_SHAPE_SHADER_HANDLE: Handle<Shader> =
+    Handle::weak_from_u128(143_261_823_470_128_573_109_872_361_094_857_361_204);
+
+pub struct NodeShapePlugin;
+
+impl Plugin for NodeShapePlugin {
+    fn build(&self, app: &mut App) {
+        load_internal_asset!(
+            app,
+            NODE_SHAPE_SHADER_HANDLE,
+            "node_shape.wgsl",
+            Shader::from_wgsl
+        );
+
+        app.add_plugins(UiMaterialPlugin::<NodeShapeMaterial>::default())
+            .configure_sets(
+                PostUpdate,
+                NodeShapeUpdate
+                    .after(DynamicStylePostUpdate)
+                    .before(UiSystem::Layout),
+            )
+            .register_type::<NodeShape>()
+            .register_type::<NodeShapeBackgroundNode>()
+            .add_systems(
+                PostUpdate,
+                (
+                    despawn_removed_node_shape_backgrounds,
+                    spawn_node_shape_backgrounds,
+                    update_node_shape_backgrounds,
+                )
+                    .chain()
+                    .in_set(NodeShapeUpdate),
+            );
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct NodeShapeUpdate;
+
+/// Radius of each corner of a node, in logical pixels.
+///
+/// Radii larger than half of the shorter side of the node are clamped.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect, Lerp)]
+pub struct CornerRadius {
+    pub top_left: f32,
+    pub top_right: f32,
+    pub bottom_right: f32,
+    pub bottom_left: f32,
+}
+
+impl From<f32> for CornerRadius {
+    fn from(value: f32) -> Self {
+        Self::all(value)
+    }
+}
+
+impl CornerRadius {
+    pub const ZERO: CornerRadius = CornerRadius {
+        top_left: 0.,
+        top_right: 0.,
+        bottom_right: 0.,
+        bottom_left: 0.,
+    };
+
+    pub fn new(top_left: f32, top_right: f32, bottom_right: f32, bottom_left: f32) -> Self {
+        Self {
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        }
+    }
+
+    pub fn all(radius: f32) -> Self {
+        Self::new(radius, radius, radius, radius)
+    }
+
+    pub fn top(radius: f32) -> Self {
+        Self::new(radius, radius, 0., 0.)
+    }
+
+    pub fn bottom(radius: f32) -> Self {
+        Self::new(0., 0., radius, radius)
+    }
+
+    pub fn left(radius: f32) -> Self {
+        Self::new(radius, 0., 0., radius)
+    }
+
+    pub fn right(radius: f32) -> Self {
+        Self::new(0., radius, radius, 0.)
+    }
+}
+
+/// Draws a rounded, bordered and shadowed background behind a node.
+///
+/// The background is rendered by a [`NodeShapeMaterial`] on an absolutely positioned child
+/// that extends beyond the node to make room for the shadow. The child is appended after the
+/// other children and marked with [`NodeShapeBackgroundNode`], so hierarchy pseudo states and
+/// layout animations skip it. Being a child, it is drawn over the node's own `BackgroundColor`
+/// and `BorderColor`, which should be left transparent.
+/// Shadows are clipped if the node clips its overflow.
+///
+/// Usually set via the `corner_radius`, `shape_*` and `shadow_*` style attributes.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct NodeShape {
+    pub corner_radius: CornerRadius,
+    pub color: Color,
+    pub border_width: f32,
+    pub border_color: Color,
+    pub shadow_color: Color,
+    pub shadow_offset: Vec2,
+    pub shadow_blur: f32,
+}
+
+impl Default for NodeShape {
+    fn default() -> Self {
+        Self {
+            corner_radius: CornerRadius::ZERO,
+            color: Color::NONE,
+            border_width: 0.,
+            border_color: Color::NONE,
+            shadow_color: Color::NONE,
+            shadow_offset: Vec2::ZERO,
+            shadow_blur: 0.,
+        }
+    }
+}
+
+impl NodeShape {
+    /// Space needed around the node to fit the shadow
+    fn padding(&self) -> f32 {
+        let offset = self.shadow_offset.abs();
+        (self.shadow_blur.max(0.) + offset.x.max(offset.y)).ceil()
+    }
+}
+
+#[derive(Asset, AsBindGroup, Clone, Copy, Debug, Default, TypePath)]
+pub struct NodeShapeMaterial {
+    #[uniform(0)]
+    pub color: Vec4,
+    #[uniform(1)]
+    pub border_color: Vec4,
+    #[uniform(2)]
+    pub shadow_color: Vec4,
+    /// Order is top left, top right, bottom right, bottom left
+    #[uniform(3)]
+    pub corner_radius: Vec4,
+    /// Order is offset x, offset y, blur, unused
+    #[uniform(4)]
+    pub shadow: Vec4,
+    /// Order is padding around the node, border width, unused, unused
+    #[uniform(5)]
+    pub extents: Vec4,
+}
+
+impl From<&NodeShape> for NodeShapeMaterial {
+    fn from(shape: &NodeShape) -> Self {
+        let radius = shape.corner_radius;
+
+        Self {
+            color: Vec4::from_array(shape.color.as_linear_rgba_f32()),
+            border_color: Vec4::from_array(shape.border_color.as_linear_rgba_f32()),
+            shadow_color: Vec4::from_array(shape.shadow_color.as_linear_rgba_f32()),
+            corner_radius: Vec4::new(
+                radius.top_left,
+                radius.top_right,
+                radius.bottom_right,
+                radius.bottom_left,
+            ),
+            shadow: shape
+                .shadow_offset
+                .extend(shape.shadow_blur.max(0.))
+                .extend(0.),
+            extents: Vec4::new(shape.padding(), shape.border_width.max(0.), 0., 0.),
+        }
+    }
+}
+
+impl UiMaterial for NodeShapeMaterial {
+    fn fragment_shader() -> ShaderRef {
+        NODE_SHAPE_SHADER_HANDLE.into()
+    }
+}
+
+/// Tracks the background entity that renders the [`NodeShape`] of a node
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+pub struct NodeShapeBackground {
+    background: Entity,
+}
+
+impl NodeShapeBackground {
+    pub fn background(&self) -> Entity {
+        self.background
+    }
+}
+
+/// Marks the child that renders the [`NodeShape`] of its parent. Logic that relies on the
+/// children of a node should skip it.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct NodeShapeBackgroundNode;
+
+fn despawn_removed_node_shape_backgrounds(
+    mut q_removed: RemovedComponents<NodeShape>,
+    q_background: Query<&NodeShapeBackground>,
+    mut commands: Commands,
+) {
+    for entity in q_removed.read() {
+        let Ok(background) = q_background.get(entity) else {
+            continue;
+        };
+
+        if let Some(background) = commands.get_entity(background.background) {
+            background.despawn_recursive();
+        }
+        commands.entity(entity).remove::<NodeShapeBackground>();
+    }
+}
+
+fn spawn_node_shape_backgrounds(
+    q_shapes: Query<(Entity, &NodeShape, &Style, Option<&NodeShapeBackground>)>,
+    q_existing: Query<(), With<Handle<NodeShapeMaterial>>>,
+    mut materials: ResMut<Assets<NodeShapeMaterial>>,
+    mut commands: Commands,
+) {
+    for (entity, shape, style, background) in &q_shapes {
+        // The background may have been despawned along with the other children of the node
+        if let Some(background) = background {
+            if q_existing.contains(background.background) {
+                continue;
+            }
+        }
+
+        let material = materials.add(NodeShapeMaterial::from(shape));
+        let background = commands
+            .spawn((
+                Name::new("Node Shape"),
+                MaterialNodeBundle {
+                    style: background_style(shape, style),
+                    material,
+                    focus_policy: FocusPolicy::Pass,
+                    z_index: ZIndex::Local(-1),
+                    ..default()
+                },
+                NodeShapeBackgroundNode,
+            ))
+            .id();
+
+        // Appended, so the indices of the other children stay the same
+        commands
+            .entity(entity)
+            .add_child(background)
+            .insert(NodeShapeBackground { background });
+    }
+}
+
+fn update_node_shape_backgrounds(
+    q_shapes: Query<
+        (&NodeShape, &Style, &NodeShapeBackground),
+        Or<(Changed<NodeShape>, Changed<Style>)>,
+    >,
+    mut q_background: Query<(&mut Style, &Handle<NodeShapeMaterial>), Without<NodeShape>>,
+    mut materials: ResMut<Assets<NodeShapeMaterial>>,
+) {
+    for (shape, style, background) in &q_shapes {
+        let Ok((mut background_style_ref, handle)) = q_background.get_mut(background.background)
+        else {
+            continue;
+        };
+
+        let new_style = background_style(shape, style);
+        if background_style_ref.left != new_style.left
+            || background_style_ref.right != new_style.right
+            || background_style_ref.top != new_style.top
+            || background_style_ref.bottom != new_style.bottom
+        {
+            *background_style_ref = new_style;
+        }
+
+        let Some(material) = materials.get_mut(handle) else {
+            continue;
+        };
+        *material = shape.into();
+    }
+}
+
+fn background_style(shape: &NodeShape, node_style: &Style) -> Style {
+    let padding = shape.padding();
+    let border_left = px_or_zero(node_style.border.left);
+    let border_right = px_or_zero(node_style.border.right);
+    let border_top = px_or_zero(node_style.border.top);
+    let border_bottom = px_or_zero(node_style.border.bottom);
+
+    // Flex layout offsets absolute children from the inner edge of the parent's border,
+    // but sizes them from its outer edge, hence the asymmetric insets.
+    Style {
+        position_type: PositionType::Absolute,
+        left: Val::Px(-(padding + border_left)),
+        right: Val::Px(border_right - padding),
+        top: Val::Px(-(padding + border_top)),
+        bottom: Val::Px(border_bottom - padding),
+        ..default()
+    }
+}
+
+fn px_or_zero(value: Val) -> f32 {
+    match value {
+        Val::Px(value) => value,
+        _ => 0.,
+    }
+}
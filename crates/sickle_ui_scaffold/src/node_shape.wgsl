@@ -0,0 +1,67 @@
+#import bevy_ui::ui_vertex_output::UiVertexOutput
+
+@group(1) @binding(0) var<uniform> color: vec4<f32>;
+@group(1) @binding(1) var<uniform> border_color: vec4<f32>;
+@group(1) @binding(2) var<uniform> shadow_color: vec4<f32>;
+// Order is top left, top right, bottom right, bottom left
+@group(1) @binding(3) var<uniform> corner_radius: vec4<f32>;
+// Order is offset x, offset y, blur, unused
+@group(1) @binding(4) var<uniform> shadow: vec4<f32>;
+// Order is padding around the node, border width, unused, unused
+@group(1) @binding(5) var<uniform> extents: vec4<f32>;
+
+// Signed distance to a box centered at the origin, with per-quadrant corner radii.
+// `point` is in pixels, y pointing down.
+fn sd_rounded_box(point: vec2<f32>, half_size: vec2<f32>, radii: vec4<f32>) -> f32 {
+    var radius = select(radii.w, radii.z, point.x > 0.0);
+    if point.y < 0.0 {
+        radius = select(radii.x, radii.y, point.x > 0.0);
+    }
+    radius = clamp(radius, 0.0, min(half_size.x, half_size.y));
+
+    let q = abs(point) - half_size + radius;
+    return length(max(q, vec2<f32>(0.0))) + min(max(q.x, q.y), 0.0) - radius;
+}
+
+fn coverage(distance: f32) -> f32 {
+    let aa = max(fwidth(distance), 0.0001);
+    return clamp(0.5 - distance / aa, 0.0, 1.0);
+}
+
+// Places `top` over `bottom` using straight (non-premultiplied) alpha
+fn blend_over(top: vec4<f32>, bottom: vec4<f32>) -> vec4<f32> {
+    let alpha = top.a + bottom.a * (1.0 - top.a);
+    if alpha <= 0.0 {
+        return vec4<f32>(0.0);
+    }
+
+    let blended = (top.rgb * top.a + bottom.rgb * bottom.a * (1.0 - top.a)) / alpha;
+    return vec4<f32>(blended, alpha);
+}
+
+@fragment
+fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
+    let padding = extents.x;
+    let border_width = extents.y;
+    let point = (in.uv - 0.5) * in.size;
+    let half_size = max(in.size * 0.5 - padding, vec2<f32>(0.0));
+
+    let distance = sd_rounded_box(point, half_size, corner_radius);
+    var fill = color;
+    if border_width > 0.0 {
+        let border_coverage = 1.0 - coverage(distance + border_width);
+        fill = mix(fill, border_color, border_coverage);
+    }
+    fill.a *= coverage(distance);
+
+    var shadow_fill = shadow_color;
+    let blur = shadow.z;
+    let shadow_distance = sd_rounded_box(point - shadow.xy, half_size, corner_radius);
+    if blur > 0.0 {
+        shadow_fill.a *= 1.0 - smoothstep(-blur, blur, shadow_distance);
+    } else {
+        shadow_fill.a *= coverage(shadow_distance);
+    }
+
+    return blend_over(fill, shadow_fill);
+}
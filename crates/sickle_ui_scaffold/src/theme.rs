@@ -3,24 +3,32 @@ pub mod dynamic_style_attribute;
 pub mod icons;
 pub mod pseudo_state;
 pub mod style_animation;
+pub mod style_class;
 pub mod theme_colors;
 pub mod theme_data;
+pub mod theme_shapes;
 pub mod theme_spacing;
 pub mod typography;
 
 use std::{
     any::{type_name, TypeId},
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     marker::PhantomData,
+    sync::Arc,
 };
 
 use bevy::{prelude::*, ui::UiSystem};
 
 use dynamic_style::{DynamicStyle, DynamicStylePlugin};
 use pseudo_state::{AutoPseudoStatePlugin, PseudoState, PseudoStates};
+use style_class::{StyleClassStyled, StyleClassThemes, StyleClasses};
 use theme_data::ThemeData;
 
-use crate::{prelude::UiBuilder, ui_commands::RefreshThemeExt, ui_style::builder::StyleBuilder};
+use crate::{
+    prelude::UiBuilder,
+    ui_commands::{RefreshStyleClassesExt, RefreshThemeExt},
+    ui_style::builder::StyleBuilder,
+};
 
 pub mod prelude {
     pub use super::{
@@ -37,8 +45,10 @@ pub mod prelude {
             AnimationLoop, AnimationSettings, AnimationState, InteractionStyle,
             LoopedAnimationConfig,
         },
+        style_class::{StyleClassThemes, StyleClasses},
         theme_colors::{Accent, Container, On, Surface},
         theme_data::{Contrast, Scheme, ThemeData},
+        theme_shapes::Elevation,
         typography::{FontScale, FontStyle, FontType, SizedFont},
         ComponentThemePlugin, CustomThemeUpdate, DefaultTheme, DynamicStyleBuilder, PseudoTheme,
         Theme, ThemeUpdate, UiContext,
@@ -55,7 +65,10 @@ impl Plugin for ThemePlugin {
         )
         .init_resource::<ThemeData>()
         .init_resource::<ThemeRegistry>()
-        .add_plugins((AutoPseudoStatePlugin, DynamicStylePlugin));
+        .init_resource::<ThemeResolutionCache>()
+        .init_resource::<StyleClassThemes>()
+        .add_plugins((AutoPseudoStatePlugin, DynamicStylePlugin))
+        .add_systems(PostUpdate, process_style_class_update.in_set(ThemeUpdate));
     }
 }
 
@@ -76,6 +89,18 @@ pub enum DynamicStyleBuilder<C> {
     ),
 }
 
+impl<C> DynamicStyleBuilder<C> {
+    pub fn variant_name(&self) -> &'static str {
+        match self {
+            DynamicStyleBuilder::Static(_) => "Static",
+            DynamicStyleBuilder::StyleBuilder(_) => "StyleBuilder",
+            DynamicStyleBuilder::ContextStyleBuilder(_) => "ContextStyleBuilder",
+            DynamicStyleBuilder::WorldStyleBuilder(_) => "WorldStyleBuilder",
+            DynamicStyleBuilder::InfoWorldStyleBuilder(_) => "InfoWorldStyleBuilder",
+        }
+    }
+}
+
 impl<C> From<StyleBuilder> for DynamicStyleBuilder<C> {
     fn from(value: StyleBuilder) -> Self {
         Self::Static(value.into())
@@ -253,6 +278,16 @@ where
         &self.pseudo_themes
     }
 
+    /// Returns `true` if any of the pseudo themes targets the state
+    pub fn targets(&self, state: &PseudoState) -> bool {
+        self.pseudo_themes.iter().any(|pseudo_theme| {
+            pseudo_theme
+                .state()
+                .as_ref()
+                .is_some_and(|states| states.contains(state))
+        })
+    }
+
     pub fn post_update() -> impl IntoSystemConfigs<()> {
         Theme::<C>::post_update_in(ThemeUpdate)
     }
@@ -274,13 +309,19 @@ where
         q_added_targets: Query<Entity, Added<C>>,
         q_removed_themes: RemovedComponents<Theme<C>>,
         q_changed_themes: Query<Entity, Changed<Theme<C>>>,
+        q_changed_classes: Query<Entity, (With<C>, Changed<StyleClasses>)>,
+        mut q_removed_classes: RemovedComponents<StyleClasses>,
         theme_data: Res<ThemeData>,
+        class_themes: Res<StyleClassThemes>,
+        mut resolution_cache: ResMut<ThemeResolutionCache>,
         mut commands: Commands,
     ) {
         if theme_data.is_changed()
+            || class_themes.is_changed()
             || q_removed_themes.len() > 0
             || q_changed_themes.iter().count() > 0
         {
+            resolution_cache.invalidate::<C>();
             for entity in &q_targets {
                 commands.entity(entity).refresh_theme::<C>();
             }
@@ -288,6 +329,18 @@ where
             for entity in &q_added_targets {
                 commands.entity(entity).refresh_theme::<C>();
             }
+
+            for entity in &q_changed_classes {
+                if !q_added_targets.contains(entity) {
+                    commands.entity(entity).refresh_theme::<C>();
+                }
+            }
+
+            for entity in q_removed_classes.read() {
+                if q_targets.contains(entity) {
+                    commands.entity(entity).refresh_theme::<C>();
+                }
+            }
         }
     }
 
@@ -309,6 +362,51 @@ where
     }
 }
 
+/// Applies the style classes of entities without a themed component.
+///
+/// Themed entities are refreshed by the theme systems of their components.
+fn process_style_class_update(
+    q_targets: Query<Entity, With<StyleClasses>>,
+    q_changed_targets: Query<
+        Entity,
+        (
+            With<StyleClasses>,
+            Or<(Changed<StyleClasses>, Changed<PseudoStates>)>,
+        ),
+    >,
+    mut q_removed_pseudo_states: RemovedComponents<PseudoStates>,
+    mut q_removed_classes: RemovedComponents<StyleClasses>,
+    q_class_styled: Query<Entity, With<StyleClassStyled>>,
+    theme_data: Res<ThemeData>,
+    class_themes: Res<StyleClassThemes>,
+    mut commands: Commands,
+) {
+    if theme_data.is_changed() || class_themes.is_changed() {
+        for entity in &q_targets {
+            commands.entity(entity).refresh_style_classes();
+        }
+    } else {
+        for entity in &q_changed_targets {
+            commands.entity(entity).refresh_style_classes();
+        }
+
+        for entity in q_removed_pseudo_states.read() {
+            if q_targets.contains(entity) {
+                commands.entity(entity).refresh_style_classes();
+            }
+        }
+    }
+
+    // Styles of themed entities are refreshed by their themes instead
+    for entity in q_removed_classes.read() {
+        if q_class_styled.contains(entity) {
+            commands
+                .entity(entity)
+                .remove::<(DynamicStyle, StyleClassStyled)>();
+        }
+    }
+}
+
 pub trait InsertThemedComponentExt {
     /// Inserts `C` as a component to the entity and checks if [`ComponentThemePlugin<C>`](ComponentThemePlugin)
     /// was added to the app.
@@ -355,6 +453,103 @@ impl ThemeRegistry {
     pub fn contains_by_id(&self, id: TypeId) -> bool {
         self.themes.contains(&id)
     }
+
+    /// Returns `true` if the entity has any of the registered themed components.
+    pub fn is_themed(&self, entity: Entity, world: &World) -> bool {
+        let Some(entity) = world.get_entity(entity) else {
+            return false;
+        };
+
+        self.themes.iter().any(|id| {
+            world
+                .components()
+                .get_id(*id)
+                .is_some_and(|component_id| entity.contains_id(component_id))
+        })
+    }
+}
+
+/// Caches which pseudo themes apply to a combination of theme chain and pseudo states,
+/// along with the output of the builders that only depend on [`ThemeData`].
+///
+/// Entries of a component are dropped when any of its themes or the theme data change.
+/// Only the pseudo states targeted by the theme chain are part of the key, so states that carry a
+/// value, like `NthChild`, only add entries when a theme styles that specific value.
+#[derive(Resource, Default, Debug)]
+pub struct ThemeResolutionCache {
+    entries: HashMap<ThemeResolutionKey, Arc<Vec<ResolvedPseudoTheme>>>,
+}
+
+impl ThemeResolutionCache {
+    /// Number of cached theme chain and pseudo state combinations
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Drops all entries of the component `C`
+    pub fn invalidate<C: 'static>(&mut self) {
+        let component = TypeId::of::<C>();
+        self.entries.retain(|key, _| key.component != component);
+    }
+
+    pub(crate) fn get(&self, key: &ThemeResolutionKey) -> Option<Arc<Vec<ResolvedPseudoTheme>>> {
+        self.entries.get(key).cloned()
+    }
+
+    pub(crate) fn insert(
+        &mut self,
+        key: ThemeResolutionKey,
+        resolution: Arc<Vec<ResolvedPseudoTheme>>,
+    ) {
+        self.entries.insert(key, resolution);
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct ThemeResolutionKey {
+    component: TypeId,
+    /// Entities of the applied themes, in order of application. `None` is the default theme.
+    themes: Vec<Option<Entity>>,
+    /// Sorted pseudo states of the themed entity that the themes target
+    pseudo_states: Vec<PseudoState>,
+    /// Change tick of the theme data the styles were built with
+    theme_data_version: u32,
+}
+
+impl ThemeResolutionKey {
+    pub(crate) fn new<C: 'static>(
+        themes: Vec<Option<Entity>>,
+        pseudo_states: &Vec<PseudoState>,
+        theme_data_version: u32,
+    ) -> Self {
+        let mut pseudo_states = pseudo_states.clone();
+        pseudo_states.sort();
+
+        Self {
+            component: TypeId::of::<C>(),
+            themes,
+            pseudo_states,
+            theme_data_version,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ResolvedPseudoTheme {
+    /// Index of the theme in the applied theme chain
+    pub theme: usize,
+    /// Index of the pseudo theme within its theme
+    pub pseudo_theme: usize,
+    /// Output of the builder, if it only depends on the theme data
+    pub style: Option<StyleBuilder>,
 }
 
 #[derive(Default)]
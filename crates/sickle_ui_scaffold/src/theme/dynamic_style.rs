@@ -1,8 +1,18 @@
+use std::sync::Arc;
+
 use bevy::{prelude::*, time::Stopwatch, ui::UiSystem};
 
 use crate::{
     flux_interaction::{FluxInteraction, StopwatchLock},
-    ui_style::{LogicalEq, UiStyleExt},
+    ui_style::{
+        generated::{
+            AnimatedStyleAttribute, InteractiveStyleAttribute, LockableStyleAttribute,
+            StaticStyleAttribute,
+        },
+        provenance::{SetStyleSource, StyleProvenance, StyleSource},
+        LogicalEq, UiStyleExt,
+    },
+    ui_time::UiTime,
 };
 
 use super::{dynamic_style_attribute::DynamicStyleAttribute, CustomThemeUpdate};
@@ -35,7 +45,7 @@ impl Plugin for DynamicStylePlugin {
 pub struct DynamicStylePostUpdate;
 
 fn tick_dynamic_style_stopwatch(
-    time: Res<Time<Real>>,
+    time: Res<UiTime>,
     mut q_stopwatches: Query<(Entity, &mut DynamicStyleStopwatch)>,
     mut commands: Commands,
 ) {
@@ -56,6 +66,7 @@ fn tick_dynamic_style_stopwatch(
 
 fn update_dynamic_style_static_attributes(
     mut q_styles: Query<(Entity, &mut DynamicStyle), Changed<DynamicStyle>>,
+    q_provenance: Query<(), With<StyleProvenance>>,
     mut commands: Commands,
 ) {
     for (entity, mut style) in &mut q_styles {
@@ -70,7 +81,13 @@ fn update_dynamic_style_static_attributes(
                 None => entity,
             };
 
-            style.apply(&mut commands.style(target));
+            apply_static_attribute(
+                target,
+                style,
+                &context_attribute.source,
+                q_provenance.contains(target),
+                &mut commands,
+            );
             had_static = true;
         }
 
@@ -100,6 +117,7 @@ fn update_dynamic_style_on_flux_change(
         ),
         Or<(Changed<DynamicStyle>, Changed<FluxInteraction>)>,
     >,
+    q_provenance: Query<(), With<StyleProvenance>>,
     mut commands: Commands,
 ) {
     for (entity, style, interaction, stopwatch) in &mut q_styles {
@@ -114,7 +132,27 @@ fn update_dynamic_style_on_flux_change(
                         None => entity,
                     };
 
-                    style.apply(*interaction, &mut commands.style(target));
+                    let Some(attribute) = style.resolve(*interaction) else {
+                        style.apply(*interaction, &mut commands.style(target));
+                        if let InteractiveStyleAttribute::Custom(callback) = style {
+                            set_style_source(
+                                target,
+                                callback.key().map(LockableStyleAttribute::Custom),
+                                &context_attribute.source,
+                                q_provenance.contains(target),
+                                &mut commands,
+                            );
+                        }
+                        continue;
+                    };
+
+                    apply_static_attribute(
+                        target,
+                        &attribute,
+                        &context_attribute.source,
+                        q_provenance.contains(target),
+                        &mut commands,
+                    );
                 }
                 DynamicStyleAttribute::Animated { controller, .. } => {
                     let animation_lock = if controller.entering() {
@@ -162,6 +200,7 @@ fn update_dynamic_style_on_stopwatch_change(
             Changed<DynamicStyleStopwatch>,
         )>,
     >,
+    q_provenance: Query<(), With<StyleProvenance>>,
     par_commands: ParallelCommands,
 ) {
     q_styles
@@ -195,8 +234,31 @@ fn update_dynamic_style_on_stopwatch_change(
                         None => entity,
                     };
 
+                    let Some(resolved) = attribute.resolve(controller.current_state()) else {
+                        par_commands.command_scope(|mut commands| {
+                            attribute
+                                .apply(controller.current_state(), &mut commands.style(target));
+                            if let AnimatedStyleAttribute::Custom(callback) = attribute {
+                                set_style_source(
+                                    target,
+                                    callback.key().map(LockableStyleAttribute::Custom),
+                                    &context_attribute.source,
+                                    q_provenance.contains(target),
+                                    &mut commands,
+                                );
+                            }
+                        });
+                        continue;
+                    };
+
                     par_commands.command_scope(|mut commands| {
-                        attribute.apply(controller.current_state(), &mut commands.style(target));
+                        apply_static_attribute(
+                            target,
+                            &resolved,
+                            &context_attribute.source,
+                            q_provenance.contains(target),
+                            &mut commands,
+                        );
                     });
                 }
 
@@ -244,6 +306,46 @@ fn update_dynamic_style_on_stopwatch_change(
         });
 }
 
+/// Applies the attribute via a style command.
+/// `tracked` is set if the target has `StyleProvenance`, in which case the command's
+/// `Manual` source is replaced by the source of the attribute.
+fn apply_static_attribute(
+    target: Entity,
+    attribute: &StaticStyleAttribute,
+    source: &Option<Arc<StyleSource>>,
+    tracked: bool,
+    commands: &mut Commands,
+) {
+    attribute.apply(&mut commands.style(target));
+    set_style_source(
+        target,
+        attribute.lockable_attribute(),
+        source,
+        tracked,
+        commands,
+    );
+}
+
+/// Replaces the `Manual` source recorded by the style command of a lockable attribute
+fn set_style_source(
+    target: Entity,
+    attr: Option<LockableStyleAttribute>,
+    source: &Option<Arc<StyleSource>>,
+    tracked: bool,
+    commands: &mut Commands,
+) {
+    if !tracked {
+        return;
+    }
+
+    if let Some(attr) = attr {
+        commands.entity(target).add(SetStyleSource {
+            attr,
+            source: source.clone(),
+        });
+    }
+}
+
 #[derive(Component, Clone, Debug, Default)]
 #[component(storage = "SparseSet")]
 pub struct DynamicStyleStopwatch(pub Stopwatch, pub StopwatchLock);
@@ -263,6 +365,7 @@ impl DynamicStyleEnterState {
 pub struct ContextStyleAttribute {
     target: Option<Entity>,
     attribute: DynamicStyleAttribute,
+    source: Option<Arc<StyleSource>>,
 }
 
 impl LogicalEq for ContextStyleAttribute {
@@ -276,8 +379,18 @@ impl ContextStyleAttribute {
         Self {
             target: context.into(),
             attribute,
+            source: None,
         }
     }
+
+    pub fn target(&self) -> Option<Entity> {
+        self.target
+    }
+
+    /// Where the attribute came from, `None` if it was not set by a theme
+    pub fn source(&self) -> Option<&StyleSource> {
+        self.source.as_deref()
+    }
 }
 
 // TODO: Consider moving to sparse set. Static styles are removed in
@@ -295,10 +408,7 @@ impl DynamicStyle {
         Self {
             attributes: attributes
                 .iter()
-                .map(|attribute| ContextStyleAttribute {
-                    target: None,
-                    attribute: attribute.clone(),
-                })
+                .map(|attribute| ContextStyleAttribute::new(None, attribute.clone()))
                 .collect(),
             enter_completed: false,
         }
@@ -370,6 +480,30 @@ impl DynamicStyle {
         }
     }
 
+    /// Sets the source of each attribute, based on the entity it targets.
+    /// Attributes without an explicit target target the `placement` entity.
+    /// `source_of` returns `None` for targets that do not track their provenance.
+    pub(crate) fn set_sources(
+        &mut self,
+        placement: Option<Entity>,
+        source_of: impl Fn(Option<Entity>) -> Option<StyleSource>,
+    ) {
+        let mut sources: Vec<(Option<Entity>, Option<Arc<StyleSource>>)> = Vec::new();
+        for context_attribute in self.attributes.iter_mut() {
+            let target = context_attribute.target.or(placement);
+            let source = match sources.iter().find(|(entity, _)| *entity == target) {
+                Some((_, source)) => source.clone(),
+                None => {
+                    let source = source_of(target).map(Arc::new);
+                    sources.push((target, source.clone()));
+                    source
+                }
+            };
+
+            context_attribute.source = source;
+        }
+    }
+
     pub fn is_interactive(&self) -> bool {
         self.attributes
             .iter()
@@ -1,9 +1,11 @@
 use std::marker::PhantomData;
 
-use bevy::{prelude::*, render::view::VisibilitySystems};
+use bevy::{prelude::*, render::view::VisibilitySystems, utils::HashSet};
 use serde::{Deserialize, Serialize};
 
-use crate::{ui_commands::ManagePseudoStateExt, CardinalDirection};
+use crate::{
+    node_shape::NodeShapeBackgroundNode, ui_commands::ManagePseudoStateExt, CardinalDirection,
+};
 
 use super::ThemeUpdate;
 
@@ -107,109 +109,128 @@ impl<C: Component> HierarchyToPseudoState<C> {
     fn should_update_hierary_pseudo_states(
         q_added_tags: Query<Entity, Added<C>>,
         q_parent_changed_tags: Query<Entity, (With<C>, Changed<Parent>)>,
+        q_changed_children: Query<Entity, Changed<Children>>,
         q_removed_tags: RemovedComponents<C>,
     ) -> bool {
         q_added_tags.iter().count() > 0
             || q_parent_changed_tags.iter().count() > 0
+            || q_changed_children.iter().count() > 0
             || q_removed_tags.len() > 0
     }
 
+    /// Updates the hierarchy pseudo states of the tagged children of parents that gained, lost
+    /// or reordered tagged children. Siblings under unchanged parents are left untouched.
     fn post_update(
-        q_nodes: Query<(Entity, &Parent), With<C>>,
+        q_added_tags: Query<&Parent, Added<C>>,
+        q_parent_changed_tags: Query<&Parent, (With<C>, Changed<Parent>)>,
+        q_changed_children: Query<Entity, Changed<Children>>,
+        mut q_removed_tags: RemovedComponents<C>,
+        q_nodes: Query<(), (With<C>, Without<NodeShapeBackgroundNode>)>,
+        q_parents: Query<&Parent>,
         q_children: Query<&Children>,
         mut q_pseudo_states: Query<&mut PseudoStates>,
         mut commands: Commands,
     ) {
-        let node_count = q_nodes.iter().len();
-        let parents: Vec<Entity> =
-            q_nodes
-                .iter()
-                .fold(Vec::with_capacity(node_count), |mut acc, (_, p)| {
-                    if !acc.contains(&p.get()) {
-                        acc.push(p.get());
-                    }
-                    acc
-                });
-
-        for parent in parents.iter() {
-            let children = q_children.get(*parent).unwrap();
-            let entities: Vec<Entity> =
-                children
-                    .iter()
-                    .fold(Vec::with_capacity(children.len()), |mut acc, child| {
-                        if q_nodes.get(*child).is_ok() {
-                            acc.push(*child);
-                        }
+        // Parents that lost a tagged child are covered by their `Children` changing,
+        // except if only the tag was removed.
+        let mut changed_parents: HashSet<Entity> = q_added_tags
+            .iter()
+            .chain(q_parent_changed_tags.iter())
+            .map(|parent| parent.get())
+            .chain(q_changed_children.iter())
+            .collect();
+        changed_parents.extend(
+            q_removed_tags
+                .read()
+                .filter_map(|entity| q_parents.get(entity).ok())
+                .map(|parent| parent.get()),
+        );
 
-                        acc
-                    });
+        for parent in changed_parents.iter() {
+            let Ok(children) = q_children.get(*parent) else {
+                continue;
+            };
 
-            for (i, entity) in entities.iter().enumerate() {
-                HierarchyToPseudoState::<C>::reset_hierarchy_entity_pseudo_states(
-                    *entity,
-                    &mut q_pseudo_states,
-                    commands.reborrow(),
-                );
-
-                let mut entity_commands = commands.entity(*entity);
+            let entities: Vec<Entity> = children
+                .iter()
+                .filter(|child| q_nodes.contains(**child))
+                .copied()
+                .collect();
 
-                if i == 0 {
-                    entity_commands.add_pseudo_state(PseudoState::FirstChild);
-                }
-                if i == entities.len() - 1 {
-                    entity_commands.add_pseudo_state(PseudoState::LastChild);
+            for (i, entity) in entities.iter().enumerate() {
+                let hierarchy_states =
+                    HierarchyToPseudoState::<C>::hierarchy_pseudo_states(i, entities.len());
+
+                let Ok(mut pseudo_states) = q_pseudo_states.get_mut(*entity) else {
+                    commands
+                        .entity(*entity)
+                        .insert(PseudoStates::from(hierarchy_states));
+                    continue;
+                };
+
+                let current_states: Vec<&PseudoState> = pseudo_states
+                    .get()
+                    .iter()
+                    .filter(|ps| HierarchyToPseudoState::<C>::is_hierarchy_pseudo_state(ps))
+                    .collect();
+                if current_states.len() == hierarchy_states.len()
+                    && hierarchy_states
+                        .iter()
+                        .all(|ps| current_states.contains(&ps))
+                {
+                    continue;
                 }
 
-                entity_commands.add_pseudo_state(PseudoState::NthChild(i));
-
-                if i % 2 == 0 {
-                    entity_commands.add_pseudo_state(PseudoState::EvenChild);
-                } else {
-                    entity_commands.add_pseudo_state(PseudoState::OddChild);
+                pseudo_states
+                    .0
+                    .retain(|ps| !HierarchyToPseudoState::<C>::is_hierarchy_pseudo_state(ps));
+                for state in hierarchy_states {
+                    pseudo_states.add(state);
                 }
             }
-
-            if entities.len() == 1 {
-                // Safe unwrap: length checked above
-                commands
-                    .entity(*entities.get(0).unwrap())
-                    .add_pseudo_state(PseudoState::SingleChild);
-            }
         }
     }
 
-    fn reset_hierarchy_entity_pseudo_states(
-        entity: Entity,
-        q_pseudo_states: &mut Query<&mut PseudoStates>,
-        mut commands: Commands,
-    ) {
-        if let Ok(mut pseudo_states) = q_pseudo_states.get_mut(entity) {
-            let to_remove: Vec<PseudoState> = pseudo_states
-                .get()
-                .iter()
-                .filter(|ps| match ps {
-                    PseudoState::FirstChild => true,
-                    PseudoState::LastChild => true,
-                    PseudoState::SingleChild => true,
-                    PseudoState::NthChild(_) => true,
-                    PseudoState::EvenChild => true,
-                    PseudoState::OddChild => true,
-                    _ => false,
-                })
-                .map(|ps| ps.clone())
-                .collect();
+    fn hierarchy_pseudo_states(index: usize, sibling_count: usize) -> Vec<PseudoState> {
+        let mut states = Vec::with_capacity(5);
 
-            for removable in to_remove {
-                pseudo_states.remove(removable);
-            }
+        if index == 0 {
+            states.push(PseudoState::FirstChild);
+        }
+        if index == sibling_count - 1 {
+            states.push(PseudoState::LastChild);
+        }
+
+        states.push(PseudoState::NthChild(index));
+
+        if index % 2 == 0 {
+            states.push(PseudoState::EvenChild);
         } else {
-            commands.entity(entity).insert(PseudoStates::new());
+            states.push(PseudoState::OddChild);
+        }
+
+        if sibling_count == 1 {
+            states.push(PseudoState::SingleChild);
+        }
+
+        states
+    }
+
+    fn is_hierarchy_pseudo_state(state: &PseudoState) -> bool {
+        match state {
+            PseudoState::FirstChild => true,
+            PseudoState::LastChild => true,
+            PseudoState::SingleChild => true,
+            PseudoState::NthChild(_) => true,
+            PseudoState::EvenChild => true,
+            PseudoState::OddChild => true,
+            _ => false,
         }
     }
 }
 
 #[derive(
-    Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Reflect, Serialize, Deserialize,
+    Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Reflect, Serialize, Deserialize,
 )]
 pub enum PseudoState {
     #[default]
@@ -233,6 +254,10 @@ pub enum PseudoState {
     Open,
     Closed,
     Error,
+    Focused,
+    DropAccepted,
+    DropRejected,
+    Dragged,
     Resizable(CardinalDirection),
     Custom(String),
 }
@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use super::{DefaultTheme, PseudoTheme, Theme, UiContext};
+
+/// Named style classes of an entity.
+///
+/// Classes are resolved against the [`StyleClassThemes`] for any entity, themed or not.
+/// On themed entities their pseudo themes are applied after all pseudo themes of the type
+/// themes, in the order the classes were added, so later classes override earlier ones.
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct StyleClasses(Vec<String>);
+
+impl<T: Into<String>> From<Vec<T>> for StyleClasses {
+    fn from(value: Vec<T>) -> Self {
+        let mut classes = StyleClasses::new();
+        for class in value {
+            classes.add(class);
+        }
+
+        classes
+    }
+}
+
+impl StyleClasses {
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    pub fn has(&self, class: &str) -> bool {
+        self.0.iter().any(|c| c == class)
+    }
+
+    pub fn add(&mut self, class: impl Into<String>) {
+        let class = class.into();
+        if !self.has(&class) {
+            self.0.push(class);
+        }
+    }
+
+    pub fn remove(&mut self, class: &str) {
+        if let Some(index) = self.0.iter().position(|c| c == class) {
+            self.0.remove(index);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get(&self) -> &Vec<String> {
+        &self.0
+    }
+}
+
+/// Marks entities whose `DynamicStyle` was built from their style classes alone
+#[derive(Component, Debug, Default)]
+pub(crate) struct StyleClassStyled;
+
+// Class pseudo themes style the entity itself, context and world builders receive its classes
+impl UiContext for StyleClasses {}
+
+impl DefaultTheme for StyleClasses {}
+
+/// Maps class names to the pseudo themes they apply to any entity that has the class.
+///
+/// Changing the registry refreshes the style of every entity with [`StyleClasses`].
+#[derive(Resource, Debug, Default)]
+pub struct StyleClassThemes {
+    classes: HashMap<String, Theme<StyleClasses>>,
+}
+
+impl StyleClassThemes {
+    /// Sets the pseudo themes of the class, replacing any previous definition
+    pub fn insert(
+        &mut self,
+        class: impl Into<String>,
+        pseudo_themes: impl Into<Vec<PseudoTheme<StyleClasses>>>,
+    ) {
+        self.classes.insert(class.into(), Theme::new(pseudo_themes));
+    }
+
+    pub fn remove(&mut self, class: &str) -> Option<Theme<StyleClasses>> {
+        self.classes.remove(class)
+    }
+
+    pub fn contains(&self, class: &str) -> bool {
+        self.classes.contains_key(class)
+    }
+
+    pub fn get(&self, class: &str) -> Option<&Theme<StyleClasses>> {
+        self.classes.get(class)
+    }
+}
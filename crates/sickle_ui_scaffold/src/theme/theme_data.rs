@@ -7,7 +7,8 @@ use crate::ui_style::builder::StyleBuilder;
 use super::{
     icons::Icons,
     style_animation::AnimationSettings,
-    theme_colors::{SchemeColors, ThemeColors},
+    theme_colors::{Accent, SchemeColors, ThemeColors},
+    theme_shapes::{ThemeElevation, ThemeShapes},
     theme_spacing::ThemeSpacing,
     typography::ThemeTypography,
 };
@@ -47,11 +48,17 @@ pub struct ThemeData {
     pub active_scheme: Scheme,
     pub colors: ThemeColors,
     pub spacing: ThemeSpacing,
+    pub shapes: ThemeShapes,
+    pub elevation: ThemeElevation,
     pub text: ThemeTypography,
     pub icons: Icons,
     pub interaction_animation: AnimationSettings,
     pub delayed_interaction_animation: AnimationSettings,
     pub enter_animation: AnimationSettings,
+    /// Seconds the pointer must rest on an entity before its tooltip shows
+    pub tooltip_delay: f32,
+    /// Opacity of draggable handles while they are dragged
+    pub dragged_opacity: f32,
 }
 
 impl Default for ThemeData {
@@ -77,11 +84,15 @@ impl Default for ThemeData {
             active_scheme: Default::default(),
             colors: Default::default(),
             spacing: Default::default(),
+            shapes: Default::default(),
+            elevation: Default::default(),
             text: Default::default(),
             icons: Default::default(),
             interaction_animation,
             delayed_interaction_animation,
             enter_animation,
+            tooltip_delay: 0.5,
+            dragged_opacity: 0.6,
         }
     }
 }
@@ -102,4 +113,33 @@ impl ThemeData {
             Scheme::Dark(contrast) => self.colors.schemes.dark.contrast(contrast),
         }
     }
+
+    /// Outlines the node with a small gap to show it has keyboard focus.
+    ///
+    /// Used as the builder of `PseudoState::Focused` pseudo themes. The base theme of the
+    /// component should hide the ring with [`ThemeData::no_focus_ring`].
+    pub fn focus_ring(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        ThemeData::outline_focus(
+            style_builder,
+            theme_data,
+            theme_data.spacing.borders.extra_small,
+        );
+    }
+
+    /// Same as [`ThemeData::focus_ring`], drawn along the inner edge for nodes that fill their container.
+    pub fn inset_focus_ring(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        ThemeData::outline_focus(style_builder, theme_data, -theme_data.spacing.borders.small);
+    }
+
+    /// Hides the focus ring once the node loses keyboard focus
+    pub fn no_focus_ring(style_builder: &mut StyleBuilder) {
+        style_builder.outline_color(Color::NONE);
+    }
+
+    fn outline_focus(style_builder: &mut StyleBuilder, theme_data: &ThemeData, offset: f32) {
+        style_builder
+            .outline_width(Val::Px(theme_data.spacing.borders.small))
+            .outline_offset(Val::Px(offset))
+            .outline_color(theme_data.colors().accent(Accent::Secondary));
+    }
 }
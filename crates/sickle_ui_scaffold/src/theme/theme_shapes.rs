@@ -0,0 +1,98 @@
+use bevy::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub enum Elevation {
+    Level0,
+    Level1,
+    Level2,
+    Level3,
+    Level4,
+    Level5,
+}
+
+/// Corner radius tokens, in logical pixels
+#[derive(Clone, Copy, Debug, Reflect)]
+pub struct ThemeShapes {
+    pub none: f32,
+    pub extra_small: f32,
+    pub small: f32,
+    pub medium: f32,
+    pub large: f32,
+    pub extra_large: f32,
+    /// Large enough to round off the shorter side of any node completely
+    pub full: f32,
+}
+
+impl Default for ThemeShapes {
+    fn default() -> Self {
+        Self {
+            none: 0.,
+            extra_small: 4.,
+            small: 8.,
+            medium: 12.,
+            large: 16.,
+            extra_large: 28.,
+            full: 9999.,
+        }
+    }
+}
+
+/// Drop shadow of a single elevation level
+#[derive(Clone, Copy, Debug, Default, Reflect)]
+pub struct ElevationLevel {
+    pub offset: Vec2,
+    pub blur: f32,
+    /// Applied to the alpha of the shadow color
+    pub opacity: f32,
+}
+
+impl ElevationLevel {
+    pub fn new(offset_y: f32, blur: f32, opacity: f32) -> Self {
+        Self {
+            offset: Vec2::new(0., offset_y),
+            blur,
+            opacity,
+        }
+    }
+
+    /// Returns `color` with the opacity of this level applied
+    pub fn shadow_color(&self, color: Color) -> Color {
+        color.with_a(color.a() * self.opacity)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Reflect)]
+pub struct ThemeElevation {
+    pub level_0: ElevationLevel,
+    pub level_1: ElevationLevel,
+    pub level_2: ElevationLevel,
+    pub level_3: ElevationLevel,
+    pub level_4: ElevationLevel,
+    pub level_5: ElevationLevel,
+}
+
+impl Default for ThemeElevation {
+    fn default() -> Self {
+        Self {
+            level_0: ElevationLevel::new(0., 0., 0.),
+            level_1: ElevationLevel::new(1., 3., 0.3),
+            level_2: ElevationLevel::new(2., 6., 0.3),
+            level_3: ElevationLevel::new(4., 8., 0.3),
+            level_4: ElevationLevel::new(6., 10., 0.3),
+            level_5: ElevationLevel::new(8., 12., 0.3),
+        }
+    }
+}
+
+impl ThemeElevation {
+    pub fn level(&self, elevation: Elevation) -> ElevationLevel {
+        match elevation {
+            Elevation::Level0 => self.level_0,
+            Elevation::Level1 => self.level_1,
+            Elevation::Level2 => self.level_2,
+            Elevation::Level3 => self.level_3,
+            Elevation::Level4 => self.level_4,
+            Elevation::Level5 => self.level_5,
+        }
+    }
+}
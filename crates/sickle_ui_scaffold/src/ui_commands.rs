@@ -1,8 +1,9 @@
-use std::marker::PhantomData;
+use std::{marker::PhantomData, sync::Arc};
 
 use bevy::{
     core::Name,
     ecs::{
+        change_detection::DetectChanges,
         component::ComponentInfo,
         entity::Entity,
         query::With,
@@ -21,8 +22,14 @@ use crate::{
         FluxInteraction, FluxInteractionStopwatchLock, StopwatchLock, TrackedInteraction,
     },
     prelude::UiUtils,
-    theme::prelude::*,
-    ui_style::builder::StyleBuilder,
+    theme::{
+        prelude::*, style_class::StyleClassStyled, ResolvedPseudoTheme, ThemeRegistry,
+        ThemeResolutionCache, ThemeResolutionKey,
+    },
+    ui_style::{
+        builder::StyleBuilder,
+        provenance::{StyleProvenance, StyleSource, ThemeStyleSource},
+    },
 };
 
 struct SetTextSections {
@@ -327,6 +334,45 @@ impl RefreshThemeExt for EntityCommands<'_> {
     }
 }
 
+pub trait RefreshStyleClassesExt {
+    /// Applies the style classes of an entity that has no themed component
+    fn refresh_style_classes(&mut self) -> &mut Self;
+}
+
+impl RefreshStyleClassesExt for EntityCommands<'_> {
+    fn refresh_style_classes(&mut self) -> &mut Self {
+        self.add(RefreshEntityStyleClasses);
+        self
+    }
+}
+
+struct RefreshEntityStyleClasses;
+
+impl EntityCommand for RefreshEntityStyleClasses {
+    fn apply(self, entity: Entity, world: &mut World) {
+        // Themed components apply the classes as part of their theme
+        if world.resource::<ThemeRegistry>().is_themed(entity, world) {
+            return;
+        }
+
+        let theme_data = world.resource::<ThemeData>().clone();
+        match build_style_class_style(entity, "StyleClasses", &theme_data, world) {
+            Some(style) => {
+                insert_dynamic_style(entity, style, world);
+                world.entity_mut(entity).insert(StyleClassStyled);
+            }
+            None => {
+                // Only clear styles the classes have set, not ones added by hand
+                if world.get::<StyleClassStyled>(entity).is_some() {
+                    world
+                        .entity_mut(entity)
+                        .remove::<(DynamicStyle, StyleClassStyled)>();
+                }
+            }
+        }
+    }
+}
+
 struct RefreshEntityTheme<C>
 where
     C: DefaultTheme,
@@ -372,6 +418,9 @@ where
             themes.push((default_theme, None));
         }
 
+        // The list contains themes in reverse order of application
+        themes.reverse();
+
         if themes.len() == 0 {
             warn!(
                 "Theme missing for component {} on entity: {:?}",
@@ -381,71 +430,89 @@ where
             return;
         }
 
-        // The list contains themes in reverse order of application
-        themes.reverse();
-
-        // Assuming we have a base style and two-three pseudo state style is a reasonable guess.
-        // TODO: Cache most common pseudo theme count in theme data.
-        let mut pseudo_themes: Vec<(&PseudoTheme<C>, Option<Entity>)> =
-            Vec::with_capacity(themes.len() * 4);
+        // States no pseudo theme targets can't change the resolution. Leaving them out keeps
+        // per-node states, like `NthChild`, from adding a cache entry per distinct value.
+        let targeted_states: Vec<PseudoState> = pseudo_states
+            .iter()
+            .filter(|state| themes.iter().any(|(theme, _)| theme.targets(state)))
+            .cloned()
+            .collect();
 
-        for (theme, source_entity) in &themes {
-            if let Some(base_theme) = theme.pseudo_themes().iter().find(|pt| pt.is_base_theme()) {
-                pseudo_themes.push((base_theme, *source_entity));
-            }
-        }
+        let theme_data_version = world.resource_ref::<ThemeData>().last_changed().get();
+        let cache_key = ThemeResolutionKey::new::<C>(
+            themes
+                .iter()
+                .map(|(_, source_entity)| *source_entity)
+                .collect(),
+            &targeted_states,
+            theme_data_version,
+        );
+        let cached_resolution = world.resource::<ThemeResolutionCache>().get(&cache_key);
+        let resolution_cached = cached_resolution.is_some();
+        let resolution = match cached_resolution {
+            Some(resolution) => resolution,
+            None => Arc::new(resolve_pseudo_themes(
+                &themes,
+                &targeted_states,
+                &theme_data,
+            )),
+        };
 
-        if pseudo_states.len() > 0 {
-            for i in 0..pseudo_states.len() {
-                for (theme, source_entity) in &themes {
-                    theme
-                        .pseudo_themes()
-                        .iter()
-                        .filter(|pt| pt.count_match(pseudo_states) == i + 1)
-                        .for_each(|pt| pseudo_themes.push((pt, *source_entity)));
-                }
-            }
-        }
+        let context_names: Vec<(&'static str, Entity)> = context
+            .contexts()
+            .iter()
+            .filter_map(|name| context.get(name).ok().map(|target| (*name, target)))
+            .collect();
 
         // Merge base attributes on top of the default and down the chain, overwriting per-attribute at each level
-        let styles: Vec<(Option<Entity>, DynamicStyle)> = pseudo_themes
+        let mut styles: Vec<(Option<Entity>, DynamicStyle)> = resolution
             .iter()
-            .map(
-                |(pseudo_theme, source_entity)| match pseudo_theme.builder() {
-                    DynamicStyleBuilder::Static(style) => vec![(None, style.clone())],
-                    DynamicStyleBuilder::StyleBuilder(builder) => {
-                        let mut style_builder = StyleBuilder::new();
-                        builder(&mut style_builder, &theme_data);
-
-                        style_builder.convert_with(context)
-                    }
-                    DynamicStyleBuilder::ContextStyleBuilder(builder) => {
-                        let mut style_builder = StyleBuilder::new();
-                        builder(&mut style_builder, &context, &theme_data);
-
-                        style_builder.convert_with(context)
-                    }
-                    DynamicStyleBuilder::WorldStyleBuilder(builder) => {
-                        let mut style_builder = StyleBuilder::new();
-                        builder(&mut style_builder, entity, &context, world);
+            .map(|resolved| {
+                let (theme, source_entity) = themes[resolved.theme];
+                let pseudo_theme = &theme.pseudo_themes()[resolved.pseudo_theme];
+
+                let mut context_styles = match &resolved.style {
+                    Some(style_builder) => style_builder.clone().convert_with(context),
+                    None => build_pseudo_theme_styles(
+                        pseudo_theme,
+                        source_entity,
+                        entity,
+                        context,
+                        &theme_data,
+                        world,
+                    ),
+                };
+
+                let source = ThemeStyleSource {
+                    component: std::any::type_name::<C>(),
+                    theme_entity: source_entity,
+                    class: None,
+                    pseudo_states: pseudo_theme.state().clone(),
+                    builder: pseudo_theme.builder().variant_name(),
+                    context: None,
+                };
+                for (placement, style) in context_styles.iter_mut() {
+                    style.set_sources(*placement, |target| {
+                        // Sources are only kept for entities that track their provenance
+                        let target_entity = target.unwrap_or(entity);
+                        world.get::<StyleProvenance>(target_entity)?;
+
+                        let context = target.and_then(|target| {
+                            context_names
+                                .iter()
+                                .find(|(_, context_entity)| *context_entity == target)
+                                .map(|(name, _)| *name)
+                        });
+
+                        Some(StyleSource::Theme(ThemeStyleSource {
+                            context,
+                            ..source.clone()
+                        }))
+                    });
+                }
 
-                        style_builder.convert_with(context)
-                    }
-                    DynamicStyleBuilder::InfoWorldStyleBuilder(builder) => {
-                        let mut style_builder = StyleBuilder::new();
-                        builder(
-                            &mut style_builder,
-                            *source_entity,
-                            pseudo_theme.state(),
-                            entity,
-                            &context,
-                            world,
-                        );
-
-                        style_builder.convert_with(context)
-                    }
-                },
-            )
+                context_styles
+            })
             .filter(|e_to_dys| e_to_dys.len() > 0)
             .fold(
                 Vec::with_capacity(context.contexts().len() + 1),
@@ -465,6 +532,19 @@ where
                 },
             );
 
+        // Style classes are applied after all pseudo themes of the type themes
+        if let Some(class_style) =
+            build_style_class_style(entity, std::any::type_name::<C>(), &theme_data, world)
+        {
+            match styles.iter().position(|(placement, _)| placement.is_none()) {
+                Some(index) => {
+                    let (_, prev_entry) = styles[index].clone();
+                    styles[index].1 = prev_entry.merge(class_style);
+                }
+                None => styles.push((None, class_style)),
+            }
+        }
+
         let mut cleanup_main_style = true;
         let mut unstyled_entities: Vec<Entity> = context
             .cleared_contexts()
@@ -475,7 +555,13 @@ where
             })
             .collect();
 
-        for (placement, mut style) in styles {
+        if !resolution_cached {
+            world
+                .resource_mut::<ThemeResolutionCache>()
+                .insert(cache_key, resolution);
+        }
+
+        for (placement, style) in styles {
             let placement_entity = match placement {
                 Some(placement_entity) => placement_entity,
                 None => {
@@ -486,26 +572,7 @@ where
 
             unstyled_entities.retain(|e| *e != placement_entity);
 
-            if let Some(current_style) = world.get::<DynamicStyle>(placement_entity) {
-                style.copy_controllers(current_style);
-            }
-
-            if style.is_interactive() || style.is_animated() {
-                world.entity_mut(placement_entity).insert(style);
-                if world.get::<Interaction>(placement_entity).is_none() {
-                    world
-                        .entity_mut(placement_entity)
-                        .insert(Interaction::default());
-                }
-
-                if world.get_mut::<FluxInteraction>(placement_entity).is_none() {
-                    world
-                        .entity_mut(placement_entity)
-                        .insert(TrackedInteraction::default());
-                }
-            } else {
-                world.entity_mut(placement_entity).insert(style);
-            }
+            insert_dynamic_style(placement_entity, style, world);
         }
 
         for unstyled_context in unstyled_entities {
@@ -518,6 +585,205 @@ where
     }
 }
 
+fn build_pseudo_theme_styles<C>(
+    pseudo_theme: &PseudoTheme<C>,
+    source_entity: Option<Entity>,
+    entity: Entity,
+    context: &C,
+    theme_data: &ThemeData,
+    world: &World,
+) -> Vec<(Option<Entity>, DynamicStyle)>
+where
+    C: DefaultTheme,
+{
+    match pseudo_theme.builder() {
+        DynamicStyleBuilder::Static(style) => vec![(None, style.clone())],
+        DynamicStyleBuilder::StyleBuilder(builder) => {
+            let mut style_builder = StyleBuilder::new();
+            builder(&mut style_builder, theme_data);
+
+            style_builder.convert_with(context)
+        }
+        DynamicStyleBuilder::ContextStyleBuilder(builder) => {
+            let mut style_builder = StyleBuilder::new();
+            builder(&mut style_builder, context, theme_data);
+
+            style_builder.convert_with(context)
+        }
+        DynamicStyleBuilder::WorldStyleBuilder(builder) => {
+            let mut style_builder = StyleBuilder::new();
+            builder(&mut style_builder, entity, context, world);
+
+            style_builder.convert_with(context)
+        }
+        DynamicStyleBuilder::InfoWorldStyleBuilder(builder) => {
+            let mut style_builder = StyleBuilder::new();
+            builder(
+                &mut style_builder,
+                source_entity,
+                pseudo_theme.state(),
+                entity,
+                context,
+                world,
+            );
+
+            style_builder.convert_with(context)
+        }
+    }
+}
+
+fn insert_dynamic_style(entity: Entity, mut style: DynamicStyle, world: &mut World) {
+    if let Some(current_style) = world.get::<DynamicStyle>(entity) {
+        style.copy_controllers(current_style);
+    }
+
+    if style.is_interactive() || style.is_animated() {
+        world.entity_mut(entity).insert(style);
+        if world.get::<Interaction>(entity).is_none() {
+            world.entity_mut(entity).insert(Interaction::default());
+        }
+
+        if world.get_mut::<FluxInteraction>(entity).is_none() {
+            world
+                .entity_mut(entity)
+                .insert(TrackedInteraction::default());
+        }
+    } else {
+        world.entity_mut(entity).insert(style);
+    }
+}
+
+/// Builds the style of the entity's [`StyleClasses`] from the [`StyleClassThemes`].
+///
+/// Base themes of the classes come first, followed by their pseudo themes by number of
+/// matching states, the same way pseudo themes of a theme chain are applied.
+fn build_style_class_style(
+    entity: Entity,
+    component: &'static str,
+    theme_data: &ThemeData,
+    world: &World,
+) -> Option<DynamicStyle> {
+    let style_classes = world.get::<StyleClasses>(entity)?;
+    let class_themes = world.get_resource::<StyleClassThemes>()?;
+    let empty_pseudo_state = Vec::new();
+    let pseudo_states = match world.get::<PseudoStates>(entity) {
+        Some(pseudo_states) => pseudo_states.get(),
+        None => &empty_pseudo_state,
+    };
+
+    let mut classes: Vec<&String> = Vec::new();
+    let mut themes: Vec<(&Theme<StyleClasses>, Option<Entity>)> = Vec::new();
+    for class in style_classes.get() {
+        if let Some(class_theme) = class_themes.get(class) {
+            classes.push(class);
+            themes.push((class_theme, None));
+        }
+    }
+
+    if themes.is_empty() {
+        return None;
+    }
+
+    resolve_pseudo_themes(&themes, pseudo_states, theme_data)
+        .into_iter()
+        .flat_map(|resolved| {
+            let (theme, _) = themes[resolved.theme];
+            let pseudo_theme = &theme.pseudo_themes()[resolved.pseudo_theme];
+
+            let mut class_styles = match resolved.style {
+                Some(style_builder) => style_builder.convert_with(style_classes),
+                None => build_pseudo_theme_styles(
+                    pseudo_theme,
+                    None,
+                    entity,
+                    style_classes,
+                    theme_data,
+                    world,
+                ),
+            };
+
+            let source = ThemeStyleSource {
+                component,
+                theme_entity: None,
+                class: classes[resolved.theme].clone().into(),
+                pseudo_states: pseudo_theme.state().clone(),
+                builder: pseudo_theme.builder().variant_name(),
+                context: None,
+            };
+            for (placement, style) in class_styles.iter_mut() {
+                style.set_sources(*placement, |target| {
+                    world.get::<StyleProvenance>(target.unwrap_or(entity))?;
+
+                    Some(StyleSource::Theme(source.clone()))
+                });
+            }
+
+            // Classes have no contexts, they only style the entity itself
+            class_styles
+                .into_iter()
+                .filter(|(placement, _)| placement.is_none())
+                .map(|(_, style)| style)
+        })
+        .reduce(|acc, style| acc.merge(style))
+}
+
+/// Selects the pseudo themes that apply to the pseudo states, in order of application.
+///
+/// Builders that only depend on the theme data are run here, so their output can be cached.
+fn resolve_pseudo_themes<C>(
+    themes: &Vec<(&Theme<C>, Option<Entity>)>,
+    pseudo_states: &Vec<PseudoState>,
+    theme_data: &ThemeData,
+) -> Vec<ResolvedPseudoTheme>
+where
+    C: DefaultTheme,
+{
+    // Assuming we have a base style and two-three pseudo state style is a reasonable guess.
+    // TODO: Cache most common pseudo theme count in theme data.
+    let mut resolution: Vec<ResolvedPseudoTheme> = Vec::with_capacity(themes.len() * 4);
+    let mut push_resolved = |theme_index: usize, pseudo_theme_index: usize| {
+        let (theme, _) = themes[theme_index];
+        let style = match theme.pseudo_themes()[pseudo_theme_index].builder() {
+            DynamicStyleBuilder::StyleBuilder(builder) => {
+                let mut style_builder = StyleBuilder::new();
+                builder(&mut style_builder, theme_data);
+
+                Some(style_builder)
+            }
+            _ => None,
+        };
+
+        resolution.push(ResolvedPseudoTheme {
+            theme: theme_index,
+            pseudo_theme: pseudo_theme_index,
+            style,
+        });
+    };
+
+    for (theme_index, (theme, _)) in themes.iter().enumerate() {
+        if let Some(base_index) = theme
+            .pseudo_themes()
+            .iter()
+            .position(|pt| pt.is_base_theme())
+        {
+            push_resolved(theme_index, base_index);
+        }
+    }
+
+    for i in 0..pseudo_states.len() {
+        for (theme_index, (theme, _)) in themes.iter().enumerate() {
+            theme
+                .pseudo_themes()
+                .iter()
+                .enumerate()
+                .filter(|(_, pt)| pt.count_match(pseudo_states) == i + 1)
+                .for_each(|(pseudo_theme_index, _)| push_resolved(theme_index, pseudo_theme_index));
+        }
+    }
+
+    resolution
+}
+
 pub trait ManageFluxInteractionStopwatchLockExt {
     fn lock_stopwatch(&mut self, owner: &'static str, duration: StopwatchLock) -> &mut Self;
 
@@ -588,3 +854,46 @@ impl ManagePseudoStateExt for EntityCommands<'_> {
         self
     }
 }
+
+pub trait ManageStyleClassExt {
+    fn add_style_class(&mut self, class: impl Into<String>) -> &mut Self;
+    fn remove_style_class(&mut self, class: impl Into<String>) -> &mut Self;
+}
+
+impl ManageStyleClassExt for EntityCommands<'_> {
+    fn add_style_class(&mut self, class: impl Into<String>) -> &mut Self {
+        let class = class.into();
+        self.add(move |entity, world: &mut World| {
+            let style_classes = world.get_mut::<StyleClasses>(entity);
+
+            if let Some(mut style_classes) = style_classes {
+                // NOTE: we must check here, as calling the add fn will trigger change detection
+                if !style_classes.has(&class) {
+                    style_classes.add(class);
+                }
+            } else {
+                let mut style_classes = StyleClasses::new();
+                style_classes.add(class);
+
+                world.entity_mut(entity).insert(style_classes);
+            }
+        });
+        self
+    }
+
+    fn remove_style_class(&mut self, class: impl Into<String>) -> &mut Self {
+        let class = class.into();
+        self.add(move |entity, world: &mut World| {
+            let Some(mut style_classes) = world.get_mut::<StyleClasses>(entity) else {
+                return;
+            };
+
+            // NOTE: we must check here, as calling the remove fn will trigger change detection
+            if style_classes.has(&class) {
+                style_classes.remove(&class);
+            }
+        });
+
+        self
+    }
+}
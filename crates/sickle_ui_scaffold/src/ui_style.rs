@@ -2,24 +2,53 @@ pub mod attribute;
 pub mod builder;
 pub mod generated;
 pub mod manual;
+pub mod provenance;
 
-use bevy::{ecs::system::EntityCommands, prelude::*, utils::HashSet};
+use bevy::{
+    ecs::system::EntityCommands,
+    prelude::*,
+    utils::{HashMap, HashSet},
+};
 
 use sickle_math::lerp::Lerp;
 
 use attribute::AnimatedVals;
 use generated::LockableStyleAttribute;
+use provenance::{StyleProvenance, StyleSource};
+
+use crate::theme::ThemeUpdate;
 
 pub mod prelude {
     pub use super::{
-        attribute::{AnimatedVals, InteractiveVals},
-        builder::StyleBuilder,
+        attribute::{
+            AnimatedVals, CustomAnimatedStyleAttribute, CustomInteractiveStyleAttribute,
+            CustomStaticStyleAttribute, InteractiveVals,
+        },
+        builder::{AnimatedStyleBuilder, InteractiveStyleBuilder, StyleBuilder},
         generated::*,
         manual::*,
+        provenance::{StyleProvenance, StyleProvenanceEntry, StyleSource, ThemeStyleSource},
         *,
     };
 }
 
+pub struct UiStylePlugin;
+
+impl Plugin for UiStylePlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(PostUpdate, StyleLockUpdate.before(ThemeUpdate))
+            .register_type::<SubtreeStyleLocks>()
+            .register_type::<StyleProvenance>()
+            .add_systems(
+                PostUpdate,
+                update_subtree_style_locks.in_set(StyleLockUpdate),
+            );
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct StyleLockUpdate;
+
 pub struct UiStyle<'a> {
     commands: EntityCommands<'a>,
 }
@@ -80,31 +109,253 @@ pub trait LogicalEq<Rhs: ?Sized = Self> {
     }
 }
 
+/// Attributes that themes and `UiStyle` commands may not change on an entity.
+///
+/// Locks are local to the entity by default. Subtree locks also apply to all descendants,
+/// unless a descendant opts out of an attribute. Opting out also releases the descendants
+/// of the opted out entity. `UiStyleUnchecked` commands ignore locks altogether.
+///
+/// Subtree locks reach the descendants in [`StyleLockUpdate`], before themes are applied.
+/// Until then, style commands on newly added descendants only see the locks they had before.
 #[derive(Component, Debug, Default, Reflect)]
-pub struct LockedStyleAttributes(HashSet<LockableStyleAttribute>);
+pub struct LockedStyleAttributes {
+    locked: HashSet<LockableStyleAttribute>,
+    subtree: HashSet<LockableStyleAttribute>,
+    opt_out: HashSet<LockableStyleAttribute>,
+}
 
 impl LockedStyleAttributes {
     pub fn new() -> Self {
-        Self(HashSet::<LockableStyleAttribute>::new())
+        Self::default()
     }
+
     pub fn lock(attributes: impl Into<HashSet<LockableStyleAttribute>>) -> Self {
-        Self(attributes.into())
+        Self {
+            locked: attributes.into(),
+            ..default()
+        }
     }
 
     pub fn from_vec(attributes: Vec<LockableStyleAttribute>) -> Self {
-        let mut set = HashSet::<LockableStyleAttribute>::with_capacity(attributes.len());
-        for attribute in attributes.iter() {
-            if !set.contains(attribute) {
-                set.insert(*attribute);
-            }
+        Self {
+            locked: HashSet::from_iter(attributes),
+            ..default()
+        }
+    }
+
+    /// Locks the attributes on the entity and all of its descendants
+    pub fn lock_subtree(attributes: impl Into<HashSet<LockableStyleAttribute>>) -> Self {
+        Self {
+            subtree: attributes.into(),
+            ..default()
+        }
+    }
+
+    pub fn subtree_from_vec(attributes: Vec<LockableStyleAttribute>) -> Self {
+        Self {
+            subtree: HashSet::from_iter(attributes),
+            ..default()
         }
+    }
+
+    /// Ignores subtree locks placed on ancestors for the attributes
+    pub fn opt_out(attributes: impl Into<HashSet<LockableStyleAttribute>>) -> Self {
+        Self {
+            opt_out: attributes.into(),
+            ..default()
+        }
+    }
+
+    pub fn with_locked(mut self, attribute: LockableStyleAttribute) -> Self {
+        self.locked.insert(attribute);
+        self
+    }
 
-        Self(set)
+    pub fn with_subtree_locked(mut self, attribute: LockableStyleAttribute) -> Self {
+        self.subtree.insert(attribute);
+        self
     }
 
+    pub fn with_opt_out(mut self, attribute: LockableStyleAttribute) -> Self {
+        self.opt_out.insert(attribute);
+        self
+    }
+
+    /// Checks locks placed on this entity only, both local and subtree
     pub fn contains(&self, attr: LockableStyleAttribute) -> bool {
-        self.0.contains(&attr)
+        self.locked.contains(&attr) || self.subtree.contains(&attr)
+    }
+
+    pub fn locks_subtree(&self, attr: LockableStyleAttribute) -> bool {
+        self.subtree.contains(&attr)
+    }
+
+    pub fn opts_out(&self, attr: LockableStyleAttribute) -> bool {
+        self.opt_out.contains(&attr)
+    }
+
+    /// Checks if the attribute is locked on the entity, either by its own locks
+    /// or by a subtree lock on one of its ancestors.
+    pub fn is_locked(entity: Entity, attr: LockableStyleAttribute, world: &World) -> bool {
+        LockedStyleAttributes::locked_by(entity, attr, world).is_some()
+    }
+
+    /// Returns the entity whose locks prevent styling the attribute: the entity itself
+    /// or the ancestor holding the subtree lock.
+    pub fn locked_by(
+        entity: Entity,
+        attr: LockableStyleAttribute,
+        world: &World,
+    ) -> Option<Entity> {
+        if let Some(locked_attrs) = world.get::<LockedStyleAttributes>(entity) {
+            if locked_attrs.contains(attr) {
+                return Some(entity);
+            }
+            if locked_attrs.opts_out(attr) {
+                return None;
+            }
+        }
+
+        world
+            .get::<SubtreeStyleLocks>(entity)
+            .and_then(|subtree_locks| subtree_locks.locked_by(attr))
+    }
+
+    /// Checks the lock for a write by a style command and records the write in the
+    /// [`StyleProvenance`] of the entity, if it has one. Returns `true` if the write is blocked.
+    pub fn check_write(
+        entity: Entity,
+        attr: LockableStyleAttribute,
+        check_lock: bool,
+        world: &mut World,
+    ) -> bool {
+        let blocked_by = match check_lock {
+            true => LockedStyleAttributes::locked_by(entity, attr, world),
+            false => None,
+        };
+        StyleProvenance::record(entity, attr, StyleSource::Manual, blocked_by, world);
+
+        blocked_by.is_some()
+    }
+
+    fn set_lock(&mut self, attribute: LockableStyleAttribute, mode: LockMode, locked: bool) {
+        let set = match mode {
+            LockMode::Local => &mut self.locked,
+            LockMode::Subtree => &mut self.subtree,
+            LockMode::OptOut => &mut self.opt_out,
+        };
+
+        if locked {
+            if !set.contains(&attribute) {
+                set.insert(attribute);
+            }
+        } else if set.contains(&attribute) {
+            set.remove(&attribute);
+        }
+    }
+}
+
+/// Subtree locks an entity inherits from its ancestors, and the ancestor holding each of them.
+///
+/// Resolved in [`StyleLockUpdate`] when locks or the hierarchy change, so lock checks don't
+/// walk the ancestors on every write. Only present on entities with inherited locks.
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct SubtreeStyleLocks {
+    locked_by: HashMap<LockableStyleAttribute, Entity>,
+}
+
+impl SubtreeStyleLocks {
+    pub fn locked_by(&self, attr: LockableStyleAttribute) -> Option<Entity> {
+        self.locked_by.get(&attr).copied()
     }
+
+    /// The locks `entity` passes on to its children
+    fn passed_on(&self, entity: Entity, locked_attrs: Option<&LockedStyleAttributes>) -> Self {
+        let Some(locked_attrs) = locked_attrs else {
+            return self.clone();
+        };
+
+        let mut locked_by = self.locked_by.clone();
+        locked_by.retain(|attr, _| !locked_attrs.opts_out(*attr));
+        for attr in locked_attrs.subtree.iter() {
+            locked_by.insert(*attr, entity);
+        }
+
+        Self { locked_by }
+    }
+}
+
+fn update_subtree_style_locks(
+    q_changed_locks: Query<Entity, Changed<LockedStyleAttributes>>,
+    mut q_removed_locks: RemovedComponents<LockedStyleAttributes>,
+    q_reparented: Query<Entity, Changed<Parent>>,
+    mut q_removed_parents: RemovedComponents<Parent>,
+    q_parent: Query<&Parent>,
+    q_children: Query<&Children>,
+    q_locks: Query<&LockedStyleAttributes>,
+    mut q_subtree_locks: Query<&mut SubtreeStyleLocks>,
+    mut commands: Commands,
+) {
+    // Locks change the subtree below the entity, moves change the moved subtree
+    let changed: HashSet<Entity> = q_changed_locks
+        .iter()
+        .chain(q_removed_locks.read())
+        .flat_map(|entity| q_children.get(entity).into_iter().flatten().copied())
+        .chain(q_reparented.iter())
+        .chain(q_removed_parents.read())
+        .collect();
+
+    // Subtrees of other roots are visited with them
+    let roots = changed.iter().filter(|entity| {
+        !q_parent
+            .iter_ancestors(**entity)
+            .any(|ancestor| changed.contains(&ancestor))
+    });
+
+    for root in roots {
+        let inherited = match q_parent.get(*root) {
+            Ok(parent) => {
+                let parent = parent.get();
+                q_subtree_locks
+                    .get(parent)
+                    .cloned()
+                    .unwrap_or_default()
+                    .passed_on(parent, q_locks.get(parent).ok())
+            }
+            Err(_) => SubtreeStyleLocks::default(),
+        };
+
+        let mut stack = vec![(*root, inherited)];
+        while let Some((entity, inherited)) = stack.pop() {
+            if let Ok(children) = q_children.get(entity) {
+                let passed_on = inherited.passed_on(entity, q_locks.get(entity).ok());
+                stack.extend(children.iter().map(|child| (*child, passed_on.clone())));
+            }
+
+            match q_subtree_locks.get_mut(entity) {
+                Ok(_) if inherited.locked_by.is_empty() => {
+                    commands.entity(entity).remove::<SubtreeStyleLocks>();
+                }
+                Ok(mut subtree_locks) => {
+                    if *subtree_locks != inherited {
+                        *subtree_locks = inherited;
+                    }
+                }
+                Err(_) if !inherited.locked_by.is_empty() => {
+                    commands.entity(entity).try_insert(inherited);
+                }
+                Err(_) => (),
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum LockMode {
+    Local,
+    Subtree,
+    OptOut,
 }
 
 impl From<LockableStyleAttribute> for HashSet<LockableStyleAttribute> {
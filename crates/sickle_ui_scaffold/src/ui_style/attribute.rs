@@ -10,6 +10,8 @@ use sickle_math::lerp::Lerp;
 
 use crate::{flux_interaction::FluxInteraction, theme::prelude::*};
 
+use super::LogicalEq;
+
 #[derive(Clone, Copy, Debug, Default, Reflect, Serialize, Deserialize)]
 pub struct InteractiveVals<T: Clone + Default> {
     pub idle: T,
@@ -152,14 +154,32 @@ impl<T: Lerp + Default + Clone + PartialEq> AnimatedVals<T> {
 #[derive(Clone)]
 pub struct CustomStaticStyleAttribute {
     pub callback: Arc<dyn Fn(Entity, &mut World) + Send + Sync + 'static>,
+    key: Option<&'static str>,
 }
 
 impl CustomStaticStyleAttribute {
     pub fn new(callback: impl Fn(Entity, &mut World) + Send + Sync + 'static) -> Self {
         Self {
             callback: Arc::new(callback),
+            key: None,
+        }
+    }
+
+    /// Creates a custom attribute that is logically equal to any other custom attribute
+    /// with the same `key`. Keyed attributes override each other when themes are merged.
+    pub fn keyed(
+        key: &'static str,
+        callback: impl Fn(Entity, &mut World) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            callback: Arc::new(callback),
+            key: Some(key),
         }
     }
+
+    pub fn key(&self) -> Option<&'static str> {
+        self.key
+    }
 }
 
 impl Debug for CustomStaticStyleAttribute {
@@ -174,9 +194,19 @@ impl PartialEq for CustomStaticStyleAttribute {
     }
 }
 
+impl LogicalEq for CustomStaticStyleAttribute {
+    fn logical_eq(&self, other: &Self) -> bool {
+        match (self.key, other.key) {
+            (Some(key), Some(other_key)) => key == other_key,
+            _ => self == other,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct CustomInteractiveStyleAttribute {
     pub callback: Arc<dyn Fn(Entity, FluxInteraction, &mut World) + Send + Sync + 'static>,
+    key: Option<&'static str>,
 }
 
 impl CustomInteractiveStyleAttribute {
@@ -185,8 +215,24 @@ impl CustomInteractiveStyleAttribute {
     ) -> Self {
         Self {
             callback: Arc::new(callback),
+            key: None,
+        }
+    }
+
+    /// Keyed variant, see [`CustomStaticStyleAttribute::keyed`].
+    pub fn keyed(
+        key: &'static str,
+        callback: impl Fn(Entity, FluxInteraction, &mut World) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            callback: Arc::new(callback),
+            key: Some(key),
         }
     }
+
+    pub fn key(&self) -> Option<&'static str> {
+        self.key
+    }
 }
 
 impl Debug for CustomInteractiveStyleAttribute {
@@ -201,9 +247,19 @@ impl PartialEq for CustomInteractiveStyleAttribute {
     }
 }
 
+impl LogicalEq for CustomInteractiveStyleAttribute {
+    fn logical_eq(&self, other: &Self) -> bool {
+        match (self.key, other.key) {
+            (Some(key), Some(other_key)) => key == other_key,
+            _ => self == other,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct CustomAnimatedStyleAttribute {
     pub callback: Arc<dyn Fn(Entity, AnimationState, &mut World) + Send + Sync + 'static>,
+    key: Option<&'static str>,
 }
 
 impl CustomAnimatedStyleAttribute {
@@ -212,8 +268,24 @@ impl CustomAnimatedStyleAttribute {
     ) -> Self {
         Self {
             callback: Arc::new(callback),
+            key: None,
         }
     }
+
+    /// Keyed variant, see [`CustomStaticStyleAttribute::keyed`].
+    pub fn keyed(
+        key: &'static str,
+        callback: impl Fn(Entity, AnimationState, &mut World) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            callback: Arc::new(callback),
+            key: Some(key),
+        }
+    }
+
+    pub fn key(&self) -> Option<&'static str> {
+        self.key
+    }
 }
 
 impl Debug for CustomAnimatedStyleAttribute {
@@ -228,6 +300,15 @@ impl PartialEq for CustomAnimatedStyleAttribute {
     }
 }
 
+impl LogicalEq for CustomAnimatedStyleAttribute {
+    fn logical_eq(&self, other: &Self) -> bool {
+        match (self.key, other.key) {
+            (Some(key), Some(other_key)) => key == other_key,
+            _ => self == other,
+        }
+    }
+}
+
 pub struct ApplyCustomStaticStyleAttribute {
     pub callback: CustomStaticStyleAttribute,
 }
@@ -60,7 +60,7 @@ impl LogicalEq for ContextStyleAttributeConfig {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct StyleBuilder {
     placement: Option<&'static str>,
     target: Option<&'static str>,
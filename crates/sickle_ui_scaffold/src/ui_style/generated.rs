@@ -1,8 +1,13 @@
-use bevy::{ecs::system::EntityCommand, prelude::*, ui::FocusPolicy};
+use bevy::{ecs::system::EntityCommand, prelude::*, text::BreakLineOn, ui::FocusPolicy};
 
 use sickle_macros::StyleCommands;
 
-use crate::{flux_interaction::FluxInteraction, theme::prelude::*};
+use crate::{
+    flux_interaction::FluxInteraction,
+    node_shape::CornerRadius,
+    theme::prelude::*,
+    ui_transform::{UiOffset, UiRotation},
+};
 
 use super::{
     attribute::{
@@ -228,10 +233,97 @@ enum _StyleAttributes {
         font_color: Color,
     },
     #[skip_enity_command]
+    InheritedFont {
+        inherited_font: String,
+    },
+    #[skip_enity_command]
+    #[animatable]
+    InheritedFontSize {
+        inherited_font_size: f32,
+    },
+    #[skip_enity_command]
+    InheritedSizedFont {
+        inherited_sized_font: SizedFont,
+    },
+    #[skip_enity_command]
+    #[animatable]
+    InheritedFontColor {
+        inherited_font_color: Color,
+    },
+    #[skip_enity_command]
+    TextJustify {
+        text_justify: JustifyText,
+    },
+    #[static_style_only]
+    #[skip_enity_command]
+    LineBreak {
+        line_break: BreakLineOn,
+    },
+    #[skip_enity_command]
+    #[animatable]
+    OutlineWidth {
+        outline_width: Val,
+    },
+    #[skip_enity_command]
+    #[animatable]
+    OutlineOffset {
+        outline_offset: Val,
+    },
+    #[skip_enity_command]
+    #[animatable]
+    OutlineColor {
+        outline_color: Color,
+    },
+    #[skip_enity_command]
+    #[animatable]
+    CornerRadius {
+        corner_radius: CornerRadius,
+    },
+    #[skip_enity_command]
+    #[animatable]
+    ShapeColor {
+        shape_color: Color,
+    },
+    #[skip_enity_command]
+    #[animatable]
+    ShapeBorderWidth {
+        shape_border_width: f32,
+    },
+    #[skip_enity_command]
+    #[animatable]
+    ShapeBorderColor {
+        shape_border_color: Color,
+    },
+    #[skip_enity_command]
+    #[animatable]
+    ShadowColor {
+        shadow_color: Color,
+    },
+    #[skip_enity_command]
+    #[animatable]
+    ShadowOffset {
+        shadow_offset: Vec2,
+    },
+    #[skip_enity_command]
+    #[animatable]
+    ShadowBlur {
+        shadow_blur: f32,
+    },
+    #[skip_enity_command]
     #[animatable]
     Scale {
         scale: f32,
     },
+    #[skip_enity_command]
+    #[animatable]
+    Translation {
+        translation: UiOffset,
+    },
+    #[skip_enity_command]
+    #[animatable]
+    Rotation {
+        rotation: UiRotation,
+    },
     #[target_enum]
     #[skip_lockable_enum]
     #[animatable]
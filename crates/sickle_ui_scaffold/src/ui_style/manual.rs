@@ -1,31 +1,40 @@
 use bevy::{ecs::system::EntityCommand, prelude::*, text::TextLayoutInfo, ui::widget::TextFlags};
 
-use crate::{flux_interaction::FluxInteraction, theme::icons::IconData};
+use crate::{
+    flux_interaction::FluxInteraction,
+    inherited_text_style::{ExplicitTextStyle, InheritedTextStyle},
+    node_shape::NodeShape,
+    theme::icons::IconData,
+    ui_transform::UiTransformOffset,
+};
 
 use super::{
-    generated::*, LockableStyleAttribute, LockedStyleAttributes, UiStyle, UiStyleUnchecked,
+    generated::*, LockMode, LockableStyleAttribute, LockedStyleAttributes, UiStyle,
+    UiStyleUnchecked,
 };
 
 // Special style-related components needing manual implementation
 macro_rules! check_lock {
-    ($world:expr, $entity:expr, $prop:literal, $lock_attr:path) => {
-        if let Some(locked_attrs) = $world.get::<LockedStyleAttributes>($entity) {
-            if locked_attrs.contains($lock_attr) {
-                warn!(
-                    "Failed to style {} property on entity {:?}: Attribute locked!",
-                    $prop, $entity
-                );
-                return;
-            }
+    ($check_lock:expr, $world:expr, $entity:expr, $prop:literal, $lock_attr:path) => {
+        if LockedStyleAttributes::check_write($entity, $lock_attr, $check_lock, $world) {
+            warn!(
+                "Failed to style {} property on entity {:?}: Attribute locked!",
+                $prop, $entity
+            );
+            return;
         }
     };
 }
 
 impl EntityCommand for SetZIndex {
     fn apply(self, entity: Entity, world: &mut World) {
-        if self.check_lock {
-            check_lock!(world, entity, "z index", LockableStyleAttribute::ZIndex);
-        }
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "z index",
+            LockableStyleAttribute::ZIndex
+        );
 
         let Some(mut z_index) = world.get_mut::<ZIndex>(entity) else {
             warn!(
@@ -82,9 +91,13 @@ pub struct SetImage {
 
 impl EntityCommand for SetImage {
     fn apply(self, entity: Entity, world: &mut World) {
-        if self.check_lock {
-            check_lock!(world, entity, "image", LockableStyleAttribute::Image);
-        }
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "image",
+            LockableStyleAttribute::Image
+        );
 
         let handle = match self.source {
             ImageSource::Path(path) => {
@@ -142,14 +155,13 @@ impl SetImageUncheckedExt for UiStyleUnchecked<'_> {
 
 impl EntityCommand for SetImageTint {
     fn apply(self, entity: Entity, world: &mut World) {
-        if self.check_lock {
-            check_lock!(
-                world,
-                entity,
-                "image tint",
-                LockableStyleAttribute::ImageTint
-            );
-        }
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "image tint",
+            LockableStyleAttribute::ImageTint
+        );
 
         // TODO: bevy 0.14: Wire to UiImage.color
         if let Some(mut backgroun_color) = world.get_mut::<BackgroundColor>(entity) {
@@ -166,14 +178,13 @@ impl EntityCommand for SetImageTint {
 
 impl EntityCommand for SetImageFlip {
     fn apply(self, entity: Entity, world: &mut World) {
-        if self.check_lock {
-            check_lock!(
-                world,
-                entity,
-                "image flip",
-                LockableStyleAttribute::ImageFlip
-            );
-        }
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "image flip",
+            LockableStyleAttribute::ImageFlip
+        );
 
         let Some(mut image) = world.get_mut::<UiImage>(entity) else {
             warn!(
@@ -195,14 +206,13 @@ impl EntityCommand for SetImageFlip {
 
 impl EntityCommand for SetImageScaleMode {
     fn apply(self, entity: Entity, world: &mut World) {
-        if self.check_lock {
-            check_lock!(
-                world,
-                entity,
-                "image scale mode",
-                LockableStyleAttribute::ImageScaleMode
-            );
-        }
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "image scale mode",
+            LockableStyleAttribute::ImageScaleMode
+        );
 
         if let Some(image_scale_mode) = self.image_scale_mode {
             if let Some(mut scale_mode) = world.get_mut::<ImageScaleMode>(entity) {
@@ -223,14 +233,13 @@ pub struct SetFluxInteractionEnabled {
 
 impl EntityCommand for SetFluxInteractionEnabled {
     fn apply(self, entity: Entity, world: &mut World) {
-        if self.check_lock {
-            check_lock!(
-                world,
-                entity,
-                "flux interaction",
-                LockableStyleAttribute::FluxInteraction
-            );
-        }
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "flux interaction",
+            LockableStyleAttribute::FluxInteraction
+        );
 
         let Some(mut flux_interaction) = world.get_mut::<FluxInteraction>(entity) else {
             warn!(
@@ -444,15 +453,20 @@ pub struct SetAbsolutePosition {
 
 impl EntityCommand for SetAbsolutePosition {
     fn apply(self, entity: Entity, world: &mut World) {
-        if self.check_lock {
-            check_lock!(world, entity, "position: top", LockableStyleAttribute::Top);
-            check_lock!(
-                world,
-                entity,
-                "position: left",
-                LockableStyleAttribute::Left
-            );
-        }
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "position: top",
+            LockableStyleAttribute::Top
+        );
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "position: left",
+            LockableStyleAttribute::Left
+        );
 
         let offset = if let Some(parent) = world.get::<Parent>(entity) {
             let Some(parent_node) = world.get::<Node>(parent.get()) else {
@@ -523,10 +537,14 @@ impl EntityCommand for SetIcon {
         // TODO: Rework once text/font is in better shape
         match self.icon {
             IconData::None => {
-                if self.check_lock {
-                    check_lock!(world, entity, "icon", LockableStyleAttribute::Image);
-                    // TODO: Check lock on text / font once it is available
-                }
+                // TODO: Check lock on text / font once it is available
+                check_lock!(
+                    self.check_lock,
+                    world,
+                    entity,
+                    "icon",
+                    LockableStyleAttribute::Image
+                );
                 SetImageTint {
                     image_tint: Color::NONE,
                     check_lock: self.check_lock,
@@ -584,6 +602,11 @@ impl EntityCommand for SetIcon {
                         TextFlags::default(),
                     ));
                 }
+
+                // Icons must not pick up inherited text styles
+                ExplicitTextStyle::mark(entity, world, |explicit| {
+                    *explicit = ExplicitTextStyle::ALL
+                });
             }
         }
     }
@@ -610,6 +633,8 @@ impl EntityCommand for SetFont {
                 section.clone()
             })
             .collect();
+
+        ExplicitTextStyle::mark(entity, world, |explicit| explicit.font = true);
     }
 }
 
@@ -631,6 +656,8 @@ impl EntityCommand for SetFontSize {
                 section.clone()
             })
             .collect();
+
+        ExplicitTextStyle::mark(entity, world, |explicit| explicit.font_size = true);
     }
 }
 
@@ -655,6 +682,11 @@ impl EntityCommand for SetSizedFont {
                 section.clone()
             })
             .collect();
+
+        ExplicitTextStyle::mark(entity, world, |explicit| {
+            explicit.font = true;
+            explicit.font_size = true;
+        });
     }
 }
 
@@ -676,29 +708,402 @@ impl EntityCommand for SetFontColor {
                 section.clone()
             })
             .collect();
+
+        ExplicitTextStyle::mark(entity, world, |explicit| explicit.color = true);
+    }
+}
+
+impl EntityCommand for SetInheritedFont {
+    fn apply(self, entity: Entity, world: &mut World) {
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "inherited font",
+            LockableStyleAttribute::InheritedFont
+        );
+
+        let font = self.inherited_font.into();
+        if let Some(mut inherited) = world.get_mut::<InheritedTextStyle>(entity) {
+            if inherited.font != font {
+                inherited.font = font;
+            }
+        } else {
+            world
+                .entity_mut(entity)
+                .insert(InheritedTextStyle { font, ..default() });
+        }
+    }
+}
+
+impl EntityCommand for SetInheritedFontSize {
+    fn apply(self, entity: Entity, world: &mut World) {
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "inherited font size",
+            LockableStyleAttribute::InheritedFontSize
+        );
+
+        let font_size = self.inherited_font_size.into();
+        if let Some(mut inherited) = world.get_mut::<InheritedTextStyle>(entity) {
+            if inherited.font_size != font_size {
+                inherited.font_size = font_size;
+            }
+        } else {
+            world.entity_mut(entity).insert(InheritedTextStyle {
+                font_size,
+                ..default()
+            });
+        }
+    }
+}
+
+impl EntityCommand for SetInheritedFontColor {
+    fn apply(self, entity: Entity, world: &mut World) {
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "inherited font color",
+            LockableStyleAttribute::InheritedFontColor
+        );
+
+        let color = self.inherited_font_color.into();
+        if let Some(mut inherited) = world.get_mut::<InheritedTextStyle>(entity) {
+            if inherited.color != color {
+                inherited.color = color;
+            }
+        } else {
+            world
+                .entity_mut(entity)
+                .insert(InheritedTextStyle { color, ..default() });
+        }
+    }
+}
+
+impl EntityCommand for SetInheritedSizedFont {
+    fn apply(self, entity: Entity, world: &mut World) {
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "inherited sized font",
+            LockableStyleAttribute::InheritedSizedFont
+        );
+
+        let font = Some(self.inherited_sized_font.font);
+        let font_size = Some(self.inherited_sized_font.size);
+        if let Some(mut inherited) = world.get_mut::<InheritedTextStyle>(entity) {
+            if inherited.font != font || inherited.font_size != font_size {
+                inherited.font = font;
+                inherited.font_size = font_size;
+            }
+        } else {
+            world.entity_mut(entity).insert(InheritedTextStyle {
+                font,
+                font_size,
+                ..default()
+            });
+        }
+    }
+}
+
+impl EntityCommand for SetTextJustify {
+    fn apply(self, entity: Entity, world: &mut World) {
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "text justify",
+            LockableStyleAttribute::TextJustify
+        );
+
+        let Some(mut text) = world.get_mut::<Text>(entity) else {
+            warn!(
+                "Failed to set text justify on entity {:?}: No Text component found!",
+                entity
+            );
+            return;
+        };
+
+        if text.justify != self.text_justify {
+            text.justify = self.text_justify;
+        }
+    }
+}
+
+impl EntityCommand for SetLineBreak {
+    fn apply(self, entity: Entity, world: &mut World) {
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "line break",
+            LockableStyleAttribute::LineBreak
+        );
+
+        let Some(mut text) = world.get_mut::<Text>(entity) else {
+            warn!(
+                "Failed to set line break on entity {:?}: No Text component found!",
+                entity
+            );
+            return;
+        };
+
+        if text.linebreak_behavior != self.line_break {
+            text.linebreak_behavior = self.line_break;
+        }
+    }
+}
+
+impl EntityCommand for SetOutlineWidth {
+    fn apply(self, entity: Entity, world: &mut World) {
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "outline width",
+            LockableStyleAttribute::OutlineWidth
+        );
+
+        if let Some(mut outline) = world.get_mut::<Outline>(entity) {
+            if outline.width != self.outline_width {
+                outline.width = self.outline_width;
+            }
+        } else {
+            world.entity_mut(entity).insert(Outline::new(
+                self.outline_width,
+                Val::ZERO,
+                Color::NONE,
+            ));
+        }
+    }
+}
+
+impl EntityCommand for SetOutlineOffset {
+    fn apply(self, entity: Entity, world: &mut World) {
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "outline offset",
+            LockableStyleAttribute::OutlineOffset
+        );
+
+        if let Some(mut outline) = world.get_mut::<Outline>(entity) {
+            if outline.offset != self.outline_offset {
+                outline.offset = self.outline_offset;
+            }
+        } else {
+            world.entity_mut(entity).insert(Outline::new(
+                Val::ZERO,
+                self.outline_offset,
+                Color::NONE,
+            ));
+        }
+    }
+}
+
+impl EntityCommand for SetOutlineColor {
+    fn apply(self, entity: Entity, world: &mut World) {
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "outline color",
+            LockableStyleAttribute::OutlineColor
+        );
+
+        if let Some(mut outline) = world.get_mut::<Outline>(entity) {
+            if outline.color != self.outline_color {
+                outline.color = self.outline_color;
+            }
+        } else {
+            world.entity_mut(entity).insert(Outline::new(
+                Val::ZERO,
+                Val::ZERO,
+                self.outline_color,
+            ));
+        }
+    }
+}
+
+impl EntityCommand for SetCornerRadius {
+    fn apply(self, entity: Entity, world: &mut World) {
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "corner radius",
+            LockableStyleAttribute::CornerRadius
+        );
+
+        if let Some(mut shape) = world.get_mut::<NodeShape>(entity) {
+            if shape.corner_radius != self.corner_radius {
+                shape.corner_radius = self.corner_radius;
+            }
+        } else {
+            world.entity_mut(entity).insert(NodeShape {
+                corner_radius: self.corner_radius,
+                ..default()
+            });
+        }
+    }
+}
+
+impl EntityCommand for SetShapeColor {
+    fn apply(self, entity: Entity, world: &mut World) {
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "shape color",
+            LockableStyleAttribute::ShapeColor
+        );
+
+        if let Some(mut shape) = world.get_mut::<NodeShape>(entity) {
+            if shape.color != self.shape_color {
+                shape.color = self.shape_color;
+            }
+        } else {
+            world.entity_mut(entity).insert(NodeShape {
+                color: self.shape_color,
+                ..default()
+            });
+        }
+    }
+}
+
+impl EntityCommand for SetShapeBorderWidth {
+    fn apply(self, entity: Entity, world: &mut World) {
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "shape border width",
+            LockableStyleAttribute::ShapeBorderWidth
+        );
+
+        if let Some(mut shape) = world.get_mut::<NodeShape>(entity) {
+            if shape.border_width != self.shape_border_width {
+                shape.border_width = self.shape_border_width;
+            }
+        } else {
+            world.entity_mut(entity).insert(NodeShape {
+                border_width: self.shape_border_width,
+                ..default()
+            });
+        }
+    }
+}
+
+impl EntityCommand for SetShapeBorderColor {
+    fn apply(self, entity: Entity, world: &mut World) {
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "shape border color",
+            LockableStyleAttribute::ShapeBorderColor
+        );
+
+        if let Some(mut shape) = world.get_mut::<NodeShape>(entity) {
+            if shape.border_color != self.shape_border_color {
+                shape.border_color = self.shape_border_color;
+            }
+        } else {
+            world.entity_mut(entity).insert(NodeShape {
+                border_color: self.shape_border_color,
+                ..default()
+            });
+        }
+    }
+}
+
+impl EntityCommand for SetShadowColor {
+    fn apply(self, entity: Entity, world: &mut World) {
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "shadow color",
+            LockableStyleAttribute::ShadowColor
+        );
+
+        if let Some(mut shape) = world.get_mut::<NodeShape>(entity) {
+            if shape.shadow_color != self.shadow_color {
+                shape.shadow_color = self.shadow_color;
+            }
+        } else {
+            world.entity_mut(entity).insert(NodeShape {
+                shadow_color: self.shadow_color,
+                ..default()
+            });
+        }
+    }
+}
+
+impl EntityCommand for SetShadowOffset {
+    fn apply(self, entity: Entity, world: &mut World) {
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "shadow offset",
+            LockableStyleAttribute::ShadowOffset
+        );
+
+        if let Some(mut shape) = world.get_mut::<NodeShape>(entity) {
+            if shape.shadow_offset != self.shadow_offset {
+                shape.shadow_offset = self.shadow_offset;
+            }
+        } else {
+            world.entity_mut(entity).insert(NodeShape {
+                shadow_offset: self.shadow_offset,
+                ..default()
+            });
+        }
+    }
+}
+
+impl EntityCommand for SetShadowBlur {
+    fn apply(self, entity: Entity, world: &mut World) {
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "shadow blur",
+            LockableStyleAttribute::ShadowBlur
+        );
+
+        if let Some(mut shape) = world.get_mut::<NodeShape>(entity) {
+            if shape.shadow_blur != self.shadow_blur {
+                shape.shadow_blur = self.shadow_blur;
+            }
+        } else {
+            world.entity_mut(entity).insert(NodeShape {
+                shadow_blur: self.shadow_blur,
+                ..default()
+            });
+        }
     }
 }
 
 struct SetLockedAttribute {
     attribute: LockableStyleAttribute,
+    mode: LockMode,
     locked: bool,
 }
 
 impl EntityCommand for SetLockedAttribute {
     fn apply(self, entity: Entity, world: &mut World) {
         if let Some(mut locked_attributes) = world.get_mut::<LockedStyleAttributes>(entity) {
-            if self.locked {
-                if !locked_attributes.contains(self.attribute) {
-                    locked_attributes.0.insert(self.attribute);
-                }
-            } else {
-                if locked_attributes.contains(self.attribute) {
-                    locked_attributes.0.remove(&self.attribute);
-                }
-            }
+            locked_attributes.set_lock(self.attribute, self.mode, self.locked);
         } else if self.locked {
             let mut locked_attributes = LockedStyleAttributes::default();
-            locked_attributes.0.insert(self.attribute);
+            locked_attributes.set_lock(self.attribute, self.mode, true);
             world.entity_mut(entity).insert(locked_attributes);
         }
     }
@@ -706,37 +1111,86 @@ impl EntityCommand for SetLockedAttribute {
 
 pub trait SetLockedAttributeExt {
     fn lock_attribute(&mut self, attribute: LockableStyleAttribute) -> &mut Self;
+    /// Locks the attribute on the entity and all of its descendants
+    fn lock_subtree_attribute(&mut self, attribute: LockableStyleAttribute) -> &mut Self;
+    /// Removes an opt out, so the entity inherits subtree locks of the attribute again
+    fn inherit_attribute_lock(&mut self, attribute: LockableStyleAttribute) -> &mut Self;
 }
 
 impl SetLockedAttributeExt for UiStyle<'_> {
     fn lock_attribute(&mut self, attribute: LockableStyleAttribute) -> &mut Self {
         self.commands.add(SetLockedAttribute {
             attribute,
+            mode: LockMode::Local,
+            locked: true,
+        });
+        self
+    }
+
+    fn lock_subtree_attribute(&mut self, attribute: LockableStyleAttribute) -> &mut Self {
+        self.commands.add(SetLockedAttribute {
+            attribute,
+            mode: LockMode::Subtree,
             locked: true,
         });
         self
     }
+
+    fn inherit_attribute_lock(&mut self, attribute: LockableStyleAttribute) -> &mut Self {
+        self.commands.add(SetLockedAttribute {
+            attribute,
+            mode: LockMode::OptOut,
+            locked: false,
+        });
+        self
+    }
 }
 
 pub trait SetLockedAttributeUncheckedExt {
     fn unlock_attribute(&mut self, attribute: LockableStyleAttribute) -> &mut Self;
+    fn unlock_subtree_attribute(&mut self, attribute: LockableStyleAttribute) -> &mut Self;
+    /// Ignores subtree locks of the attribute placed on ancestors of the entity
+    fn opt_out_of_attribute_lock(&mut self, attribute: LockableStyleAttribute) -> &mut Self;
 }
 
 impl SetLockedAttributeUncheckedExt for UiStyleUnchecked<'_> {
     fn unlock_attribute(&mut self, attribute: LockableStyleAttribute) -> &mut Self {
         self.commands.add(SetLockedAttribute {
             attribute,
+            mode: LockMode::Local,
+            locked: false,
+        });
+        self
+    }
+
+    fn unlock_subtree_attribute(&mut self, attribute: LockableStyleAttribute) -> &mut Self {
+        self.commands.add(SetLockedAttribute {
+            attribute,
+            mode: LockMode::Subtree,
             locked: false,
         });
         self
     }
+
+    fn opt_out_of_attribute_lock(&mut self, attribute: LockableStyleAttribute) -> &mut Self {
+        self.commands.add(SetLockedAttribute {
+            attribute,
+            mode: LockMode::OptOut,
+            locked: true,
+        });
+        self
+    }
 }
 
 impl EntityCommand for SetScale {
     fn apply(self, entity: Entity, world: &mut World) {
-        if self.check_lock {
-            check_lock!(world, entity, "scale", LockableStyleAttribute::Scale);
-        }
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "scale",
+            LockableStyleAttribute::Scale
+        );
 
         let Some(mut transform) = world.get_mut::<Transform>(entity) else {
             warn!(
@@ -753,17 +1207,68 @@ impl EntityCommand for SetScale {
     }
 }
 
-impl EntityCommand for SetSize {
+impl EntityCommand for SetTranslation {
     fn apply(self, entity: Entity, world: &mut World) {
-        if self.check_lock {
-            check_lock!(world, entity, "size: width", LockableStyleAttribute::Width);
-            check_lock!(
-                world,
-                entity,
-                "size: height",
-                LockableStyleAttribute::Height
-            );
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "translation",
+            LockableStyleAttribute::Translation
+        );
+
+        if let Some(mut offset) = world.get_mut::<UiTransformOffset>(entity) {
+            if offset.translation != self.translation {
+                offset.translation = self.translation;
+            }
+        } else {
+            world.entity_mut(entity).insert(UiTransformOffset {
+                translation: self.translation,
+                ..default()
+            });
         }
+    }
+}
+
+impl EntityCommand for SetRotation {
+    fn apply(self, entity: Entity, world: &mut World) {
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "rotation",
+            LockableStyleAttribute::Rotation
+        );
+
+        if let Some(mut offset) = world.get_mut::<UiTransformOffset>(entity) {
+            if offset.rotation != self.rotation {
+                offset.rotation = self.rotation;
+            }
+        } else {
+            world.entity_mut(entity).insert(UiTransformOffset {
+                rotation: self.rotation,
+                ..default()
+            });
+        }
+    }
+}
+
+impl EntityCommand for SetSize {
+    fn apply(self, entity: Entity, world: &mut World) {
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "size: width",
+            LockableStyleAttribute::Width
+        );
+        check_lock!(
+            self.check_lock,
+            world,
+            entity,
+            "size: height",
+            LockableStyleAttribute::Height
+        );
 
         let Some(mut style) = world.get_mut::<Style>(entity) else {
             warn!(
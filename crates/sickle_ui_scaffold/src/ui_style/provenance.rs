@@ -0,0 +1,143 @@
+use std::sync::Arc;
+
+use bevy::{core::FrameCount, ecs::system::EntityCommand, prelude::*, utils::HashMap};
+
+use crate::theme::pseudo_state::PseudoState;
+
+use super::generated::LockableStyleAttribute;
+
+/// Where the value of a style attribute came from
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub enum StyleSource {
+    /// A `UiStyle` or `UiStyleUnchecked` command
+    Manual,
+    /// A `DynamicStyle` that was not produced by a theme
+    DynamicStyle,
+    /// A `PseudoTheme` of a component theme
+    Theme(ThemeStyleSource),
+}
+
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct ThemeStyleSource {
+    /// Type name of the themed component
+    pub component: &'static str,
+    /// Entity holding the `Theme`, `None` for the default theme of the component and style classes
+    pub theme_entity: Option<Entity>,
+    /// Style class the pseudo theme was registered for, `None` for type themes
+    pub class: Option<String>,
+    /// Pseudo states that select the pseudo theme, `None` for base themes
+    pub pseudo_states: Option<Vec<PseudoState>>,
+    /// Variant of the `DynamicStyleBuilder` of the pseudo theme
+    pub builder: &'static str,
+    /// UI context the attribute was written to, `None` if it was the themed entity itself
+    pub context: Option<&'static str>,
+}
+
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct StyleProvenanceEntry {
+    pub source: StyleSource,
+    /// Value of `FrameCount` when the attribute was last written
+    pub frame: u32,
+    /// Entity whose `LockedStyleAttributes` rejected the write, if it was rejected
+    pub blocked_by: Option<Entity>,
+}
+
+impl StyleProvenanceEntry {
+    pub fn blocked(&self) -> bool {
+        self.blocked_by.is_some()
+    }
+}
+
+/// Records the source of the last write of each lockable style attribute of the entity.
+///
+/// Opt-in: only entities with this component are tracked. Insert it on a node to find out
+/// which theme, dynamic style or command last styled it, and which writes were rejected
+/// by attribute locks.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct StyleProvenance {
+    entries: HashMap<LockableStyleAttribute, StyleProvenanceEntry>,
+}
+
+impl StyleProvenance {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, attr: LockableStyleAttribute) -> Option<&StyleProvenanceEntry> {
+        self.entries.get(&attr)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&LockableStyleAttribute, &StyleProvenanceEntry)> {
+        self.entries.iter()
+    }
+
+    /// Attributes whose last write was rejected by a lock
+    pub fn blocked(
+        &self,
+    ) -> impl Iterator<Item = (&LockableStyleAttribute, &StyleProvenanceEntry)> {
+        self.entries.iter().filter(|(_, entry)| entry.blocked())
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub(crate) fn record_write(
+        &mut self,
+        attr: LockableStyleAttribute,
+        source: StyleSource,
+        frame: u32,
+        blocked_by: Option<Entity>,
+    ) {
+        self.entries.insert(
+            attr,
+            StyleProvenanceEntry {
+                source,
+                frame,
+                blocked_by,
+            },
+        );
+    }
+
+    pub(crate) fn record(
+        entity: Entity,
+        attr: LockableStyleAttribute,
+        source: StyleSource,
+        blocked_by: Option<Entity>,
+        world: &mut World,
+    ) {
+        let frame = world
+            .get_resource::<FrameCount>()
+            .map_or(0, |frame_count| frame_count.0);
+
+        if let Some(mut provenance) = world.get_mut::<StyleProvenance>(entity) {
+            provenance.record_write(attr, source, frame, blocked_by);
+        }
+    }
+}
+
+/// Replaces the source of the last recorded write of an attribute.
+///
+/// Used when a style is applied via a command on behalf of a dynamic style or theme.
+pub(crate) struct SetStyleSource {
+    pub attr: LockableStyleAttribute,
+    pub source: Option<Arc<StyleSource>>,
+}
+
+impl EntityCommand for SetStyleSource {
+    fn apply(self, entity: Entity, world: &mut World) {
+        let Some(mut provenance) = world.get_mut::<StyleProvenance>(entity) else {
+            return;
+        };
+
+        let Some(entry) = provenance.entries.get_mut(&self.attr) else {
+            return;
+        };
+
+        entry.source = match self.source {
+            Some(source) => source.as_ref().clone(),
+            None => StyleSource::DynamicStyle,
+        };
+    }
+}
@@ -0,0 +1,165 @@
+use std::time::Duration;
+
+use bevy::{prelude::*, time::TimeSystem};
+
+pub struct UiTimePlugin;
+
+impl Plugin for UiTimePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UiTime>()
+            .register_type::<UiTime>()
+            .configure_sets(First, UiTimeUpdate.after(TimeSystem))
+            .add_systems(First, update_ui_time.in_set(UiTimeUpdate));
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct UiTimeUpdate;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Reflect)]
+pub enum UiTimeMode {
+    /// Advances with [`Time<Real>`], scaled by the relative speed.
+    #[default]
+    Real,
+    /// Only advances by the durations passed to [`UiTime::advance_by`].
+    ///
+    /// Useful for deterministic, headless tests of style animations.
+    Manual,
+}
+
+/// Clock used by all sickle stopwatches ([`FluxInteractionStopwatch`](crate::flux_interaction::FluxInteractionStopwatch),
+/// [`DynamicStyleStopwatch`](crate::theme::dynamic_style::DynamicStyleStopwatch)).
+///
+/// Can be paused, slowed down or sped up, and stepped frame-by-frame while paused
+/// to inspect animations.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct UiTime {
+    mode: UiTimeMode,
+    relative_speed: f32,
+    paused: bool,
+    pending_step: Duration,
+    pending_advance: Duration,
+    delta: Duration,
+    elapsed: Duration,
+}
+
+impl Default for UiTime {
+    fn default() -> Self {
+        Self {
+            mode: Default::default(),
+            relative_speed: 1.,
+            paused: false,
+            pending_step: Duration::ZERO,
+            pending_advance: Duration::ZERO,
+            delta: Duration::ZERO,
+            elapsed: Duration::ZERO,
+        }
+    }
+}
+
+impl UiTime {
+    /// Creates a clock in [`UiTimeMode::Manual`] mode
+    pub fn manual() -> Self {
+        Self {
+            mode: UiTimeMode::Manual,
+            ..default()
+        }
+    }
+
+    pub fn mode(&self) -> UiTimeMode {
+        self.mode
+    }
+
+    pub fn set_mode(&mut self, mode: UiTimeMode) {
+        self.mode = mode;
+        self.pending_advance = Duration::ZERO;
+    }
+
+    /// Time passed since the last frame, after scaling and pausing is applied
+    pub fn delta(&self) -> Duration {
+        self.delta
+    }
+
+    pub fn delta_seconds(&self) -> f32 {
+        self.delta.as_secs_f32()
+    }
+
+    /// Total time the UI clock has advanced
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    pub fn elapsed_seconds(&self) -> f32 {
+        self.elapsed.as_secs_f32()
+    }
+
+    pub fn relative_speed(&self) -> f32 {
+        self.relative_speed
+    }
+
+    /// Sets the time scale of the clock. A speed of `0.5` plays animations at half speed.
+    ///
+    /// Panics if `ratio` is negative or not finite.
+    pub fn set_relative_speed(&mut self, ratio: f32) {
+        assert!(ratio.is_finite(), "tried to go infinitely fast");
+        assert!(ratio >= 0., "tried to go back in time");
+        self.relative_speed = ratio;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    pub fn unpause(&mut self) {
+        self.paused = false;
+    }
+
+    pub fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+    }
+
+    /// Advances the clock by exactly `duration` on the next frame, regardless
+    /// of pause state and relative speed.
+    ///
+    /// Use while paused to step through animations frame-by-frame.
+    pub fn step(&mut self, duration: Duration) {
+        self.pending_step += duration;
+    }
+
+    /// Queues `duration` to be consumed by the next frame in [`UiTimeMode::Manual`] mode.
+    ///
+    /// The queued time is subject to pause and relative speed, just like real time.
+    /// Has no effect in [`UiTimeMode::Real`] mode.
+    pub fn advance_by(&mut self, duration: Duration) {
+        if self.mode == UiTimeMode::Real {
+            warn!("UiTime::advance_by called in real time mode, ignored");
+            return;
+        }
+
+        self.pending_advance += duration;
+    }
+
+    fn update_with(&mut self, real_delta: Duration) {
+        let raw_delta = match self.mode {
+            UiTimeMode::Real => real_delta,
+            UiTimeMode::Manual => std::mem::take(&mut self.pending_advance),
+        };
+
+        let scaled_delta = match self.paused {
+            true => Duration::ZERO,
+            false => raw_delta.mul_f32(self.relative_speed),
+        };
+
+        self.delta = scaled_delta + std::mem::take(&mut self.pending_step);
+        self.elapsed += self.delta;
+    }
+}
+
+fn update_ui_time(time: Res<Time<Real>>, mut ui_time: ResMut<UiTime>) {
+    ui_time.update_with(time.delta());
+}
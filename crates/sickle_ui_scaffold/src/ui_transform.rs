@@ -0,0 +1,204 @@
+use bevy::{prelude::*, transform::TransformSystem, ui::UiSystem, window::PrimaryWindow};
+
+use sickle_macros::Lerp;
+use sickle_math::lerp::Lerp;
+
+pub struct UiTransformPlugin;
+
+impl Plugin for UiTransformPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(
+            PostUpdate,
+            UiTransformUpdate
+                .after(UiSystem::Layout)
+                .before(TransformSystem::TransformPropagate),
+        )
+        .add_systems(
+            PostUpdate,
+            (
+                reset_removed_ui_transform_offsets,
+                apply_ui_transform_offsets,
+            )
+                .chain()
+                .in_set(UiTransformUpdate),
+        );
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct UiTransformUpdate;
+
+/// A 2D offset expressed in UI values.
+///
+/// Percent values are relative to the size of the node itself, not its parent.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect, Lerp)]
+pub struct UiOffset {
+    pub x: Val,
+    pub y: Val,
+}
+
+impl Default for UiOffset {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<Vec2> for UiOffset {
+    fn from(value: Vec2) -> Self {
+        Self::px(value.x, value.y)
+    }
+}
+
+impl From<(Val, Val)> for UiOffset {
+    fn from(value: (Val, Val)) -> Self {
+        Self::new(value.0, value.1)
+    }
+}
+
+impl UiOffset {
+    pub const ZERO: UiOffset = UiOffset {
+        x: Val::Px(0.),
+        y: Val::Px(0.),
+    };
+
+    pub const CENTER: UiOffset = UiOffset {
+        x: Val::Percent(50.),
+        y: Val::Percent(50.),
+    };
+
+    pub fn new(x: Val, y: Val) -> Self {
+        Self { x, y }
+    }
+
+    pub fn px(x: f32, y: f32) -> Self {
+        Self {
+            x: Val::Px(x),
+            y: Val::Px(y),
+        }
+    }
+
+    pub fn percent(x: f32, y: f32) -> Self {
+        Self {
+            x: Val::Percent(x),
+            y: Val::Percent(y),
+        }
+    }
+
+    /// Resolves the offset to logical pixels. `Val::Auto` resolves to zero.
+    pub fn resolve(&self, node_size: Vec2, viewport_size: Vec2) -> Vec2 {
+        Vec2::new(
+            self.x.resolve(node_size.x, viewport_size).unwrap_or(0.),
+            self.y.resolve(node_size.y, viewport_size).unwrap_or(0.),
+        )
+    }
+}
+
+/// Rotation of a node in radians (clockwise on screen), around an optional pivot.
+///
+/// The pivot is measured from the top-left corner of the node. When no pivot is set,
+/// the node rotates around its center.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+pub struct UiRotation {
+    pub angle: f32,
+    #[reflect(default)]
+    pub pivot: Option<UiOffset>,
+}
+
+impl From<f32> for UiRotation {
+    fn from(angle: f32) -> Self {
+        Self::new(angle)
+    }
+}
+
+impl Lerp for UiRotation {
+    fn lerp(&self, to: Self, t: f32) -> Self {
+        let pivot = match (self.pivot, to.pivot) {
+            (Some(from), Some(to)) => from.lerp(to, t).into(),
+            _ => match t < 1. {
+                true => self.pivot,
+                false => to.pivot,
+            },
+        };
+
+        Self {
+            angle: self.angle.lerp(to.angle, t),
+            pivot,
+        }
+    }
+}
+
+impl UiRotation {
+    pub fn new(angle: f32) -> Self {
+        Self { angle, pivot: None }
+    }
+
+    pub fn degrees(degrees: f32) -> Self {
+        Self::new(degrees.to_radians())
+    }
+
+    pub fn with_pivot(self, pivot: impl Into<UiOffset>) -> Self {
+        Self {
+            pivot: Some(pivot.into()),
+            ..self
+        }
+    }
+}
+
+/// Visual transform applied on top of the computed layout of a node.
+///
+/// Unlike `Margin` or `Left`, these values do not affect flex layout of the node
+/// or its siblings. Set via the `translation` and `rotation` style attributes.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiTransformOffset {
+    pub translation: UiOffset,
+    pub rotation: UiRotation,
+}
+
+fn reset_removed_ui_transform_offsets(
+    mut q_removed: RemovedComponents<UiTransformOffset>,
+    mut q_transform: Query<&mut Transform, With<Node>>,
+) {
+    for entity in q_removed.read() {
+        let Ok(mut transform) = q_transform.get_mut(entity) else {
+            continue;
+        };
+
+        // Translation is restored by the layout on the next run
+        transform.rotation = Quat::IDENTITY;
+    }
+}
+
+fn apply_ui_transform_offsets(
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    mut q_nodes: Query<(&Node, &UiTransformOffset, &mut Transform)>,
+) {
+    let viewport_size = match q_window.get_single() {
+        Ok(window) => Vec2::new(window.width(), window.height()),
+        Err(_) => Vec2::ZERO,
+    };
+
+    for (node, offset, mut transform) in &mut q_nodes {
+        let size = node.size();
+        let rotation = Quat::from_rotation_z(offset.rotation.angle);
+
+        if transform.rotation != rotation {
+            transform.rotation = rotation;
+        }
+
+        // Keep the pivot in place by compensating the translation
+        let pivot_compensation = match offset.rotation.pivot {
+            Some(pivot) => {
+                let pivot = pivot.resolve(size, viewport_size) - (size / 2.);
+                let transformed_pivot = rotation * (transform.scale * pivot.extend(0.));
+                pivot - transformed_pivot.truncate()
+            }
+            None => Vec2::ZERO,
+        };
+
+        let translation = offset.translation.resolve(size, viewport_size) + pivot_compensation;
+        if translation != Vec2::ZERO {
+            transform.translation += translation.extend(0.);
+        }
+    }
+}
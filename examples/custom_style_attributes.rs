@@ -0,0 +1,148 @@
+//! An example of theming the fields of a custom component, generated by `#[derive(StyleAttributes)]`.
+//!
+//! The meters fill up and change color on hover, animated by their theme.
+use bevy::prelude::*;
+
+use sickle_ui::{prelude::*, SickleUiPlugin};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "Sickle UI -  Custom Style Attributes".into(),
+                resolution: (800., 600.).into(),
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_plugins(SickleUiPlugin)
+        .add_plugins(ComponentThemePlugin::<Meter>::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, update_meter_bars.after(DynamicStylePostUpdate))
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+
+    commands.ui_builder(UiRoot).column(|column| {
+        column
+            .style()
+            .width(Val::Percent(100.))
+            .height(Val::Percent(100.))
+            .justify_content(JustifyContent::Center)
+            .align_items(AlignItems::Center)
+            .row_gap(Val::Px(20.));
+
+        for _ in 0..3 {
+            column.meter();
+        }
+    });
+}
+
+fn update_meter_bars(
+    q_meters: Query<&Meter, Changed<Meter>>,
+    mut q_bars: Query<(&mut Style, &mut BackgroundColor)>,
+) {
+    for meter in &q_meters {
+        let Ok((mut style, mut background_color)) = q_bars.get_mut(meter.bar) else {
+            continue;
+        };
+
+        style.width = Val::Percent(meter.fill * 100.);
+        background_color.0 = meter.fill_color;
+    }
+}
+
+#[derive(Component, Clone, Debug, Reflect, UiContext, StyleAttributes)]
+#[reflect(Component)]
+pub struct Meter {
+    /// Filled ratio, between 0 and 1
+    #[animatable]
+    fill: f32,
+    #[animatable]
+    fill_color: Color,
+    #[skip_style]
+    bar: Entity,
+}
+
+impl Default for Meter {
+    fn default() -> Self {
+        Self {
+            fill: 0.,
+            fill_color: Color::NONE,
+            bar: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+impl DefaultTheme for Meter {
+    fn default_theme() -> Option<Theme<Meter>> {
+        Meter::theme().into()
+    }
+}
+
+impl Meter {
+    pub fn theme() -> Theme<Meter> {
+        let base_theme = PseudoTheme::deferred(None, Meter::primary_style);
+        Theme::new(vec![base_theme])
+    }
+
+    fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing;
+        let colors = theme_data.colors();
+
+        style_builder
+            .width(Val::Px(theme_spacing.areas.extra_large))
+            .height(Val::Px(theme_spacing.areas.small))
+            .background_color(colors.surface(Surface::SurfaceVariant));
+
+        style_builder
+            .animated()
+            .meter_fill(AnimatedVals {
+                idle: 0.25,
+                hover: Some(0.9),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+
+        style_builder
+            .animated()
+            .meter_fill_color(AnimatedVals {
+                idle: colors.accent(Accent::Primary),
+                hover: colors.accent(Accent::Tertiary).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+    }
+
+    fn frame() -> impl Bundle {
+        (
+            Name::new("Meter"),
+            NodeBundle::default(),
+            Interaction::default(),
+            TrackedInteraction::default(),
+        )
+    }
+}
+
+pub trait UiMeterExt {
+    fn meter(&mut self) -> UiBuilder<'_, Entity>;
+}
+
+impl UiMeterExt for UiBuilder<'_, Entity> {
+    fn meter(&mut self) -> UiBuilder<'_, Entity> {
+        let mut bar = Entity::PLACEHOLDER;
+        let mut meter = self.container(Meter::frame(), |container| {
+            bar = container
+                .spawn((Name::new("Meter Bar"), NodeBundle::default()))
+                .style()
+                .height(Val::Percent(100.))
+                .id();
+        });
+
+        meter.insert(Meter { bar, ..default() });
+
+        meter
+    }
+}
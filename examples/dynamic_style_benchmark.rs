@@ -0,0 +1,192 @@
+//! [Epilepsy WARNING] Measures the frame cost of evaluating and applying animated DynamicStyles.
+//!
+//! Spawns 5,000 nodes with continuously looping animations and logs the average time spent in
+//! `DynamicStylePostUpdate` along with the average frame time.
+//!
+//! Pass `--headless` to run without a window or renderer, for `REPORT_COUNT` reports.
+use std::time::{Duration, Instant};
+
+use bevy::{
+    app::{AppExit, ScheduleRunnerPlugin},
+    prelude::*,
+    render::{settings::WgpuSettings, RenderPlugin},
+    window::PresentMode,
+    winit::WinitPlugin,
+};
+
+use sickle_math::ease::Ease;
+use sickle_ui::{
+    flux_interaction::TrackedInteraction,
+    theme::{
+        dynamic_style::{DynamicStyle, DynamicStylePostUpdate},
+        style_animation::AnimationLoop,
+    },
+    ui_style::prelude::*,
+    SickleUiPlugin,
+};
+
+const NODE_COUNT: usize = 5_000;
+const NODE_SIZE: f32 = 12.;
+const REPORT_EVERY_FRAMES: u32 = 300;
+const REPORT_COUNT: u32 = 5;
+
+fn main() {
+    let headless = std::env::args().any(|arg| arg == "--headless");
+    let default_plugins = DefaultPlugins.set(WindowPlugin {
+        primary_window: Some(Window {
+            title: "Sickle UI -  Dynamic Style Benchmark".into(),
+            present_mode: PresentMode::Immediate,
+            ..default()
+        }),
+        ..default()
+    });
+
+    let mut app = App::new();
+    if headless {
+        // The window entity still provides the viewport size for the layout
+        app.add_plugins((
+            default_plugins
+                .set(RenderPlugin {
+                    render_creation: WgpuSettings {
+                        backends: None,
+                        ..default()
+                    }
+                    .into(),
+                    ..default()
+                })
+                .disable::<WinitPlugin>(),
+            ScheduleRunnerPlugin::run_loop(Duration::ZERO),
+        ))
+        .insert_resource(ReportLimit(REPORT_COUNT.into()));
+    } else {
+        app.add_plugins(default_plugins)
+            .insert_resource(ReportLimit(None));
+    }
+
+    app.add_plugins(SickleUiPlugin)
+        .init_resource::<BenchmarkTimings>()
+        .add_systems(Startup, setup)
+        .add_systems(
+            PostUpdate,
+            (
+                start_dynamic_style_timer.before(DynamicStylePostUpdate),
+                stop_dynamic_style_timer.after(DynamicStylePostUpdate),
+            ),
+        )
+        .add_systems(Last, report_timings)
+        .run();
+}
+
+/// Reports left before the app exits, if limited
+#[derive(Resource)]
+struct ReportLimit(Option<u32>);
+
+#[derive(Resource, Default)]
+struct BenchmarkTimings {
+    dynamic_style_start: Option<Instant>,
+    dynamic_style_total: Duration,
+    frame_total: Duration,
+    frames: u32,
+}
+
+fn start_dynamic_style_timer(mut timings: ResMut<BenchmarkTimings>) {
+    timings.dynamic_style_start = Some(Instant::now());
+}
+
+fn stop_dynamic_style_timer(mut timings: ResMut<BenchmarkTimings>) {
+    if let Some(start) = timings.dynamic_style_start.take() {
+        timings.dynamic_style_total += start.elapsed();
+    }
+}
+
+fn report_timings(
+    time: Res<Time<Real>>,
+    mut timings: ResMut<BenchmarkTimings>,
+    mut limit: ResMut<ReportLimit>,
+    mut app_exit: EventWriter<AppExit>,
+) {
+    timings.frame_total += time.delta();
+    timings.frames += 1;
+
+    if timings.frames < REPORT_EVERY_FRAMES {
+        return;
+    }
+
+    let frames = timings.frames as f64;
+    info!(
+        "{} animated nodes: dynamic styles {:.3} ms/frame, frame {:.3} ms",
+        NODE_COUNT,
+        timings.dynamic_style_total.as_secs_f64() * 1000. / frames,
+        timings.frame_total.as_secs_f64() * 1000. / frames,
+    );
+
+    *timings = BenchmarkTimings::default();
+
+    if let Some(remaining) = limit.0.as_mut() {
+        *remaining -= 1;
+        if *remaining == 0 {
+            app_exit.send(AppExit);
+        }
+    }
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                flex_wrap: FlexWrap::Wrap,
+                align_content: AlignContent::FlexStart,
+                ..default()
+            },
+            background_color: Color::BLACK.into(),
+            ..default()
+        })
+        .with_children(|container| {
+            for i in 0..NODE_COUNT {
+                container.spawn((
+                    NodeBundle::default(),
+                    TrackedInteraction::default(),
+                    animated_style(i),
+                ));
+            }
+        });
+}
+
+fn animated_style(index: usize) -> DynamicStyle {
+    // Offset the animations so nodes don't all update in lockstep
+    let delay = (index % 100) as f32 / 100.;
+    let mut builder = StyleBuilder::new();
+
+    builder
+        .animated()
+        .background_color(AnimatedVals {
+            idle: Color::rgb(0.2, 0.2, 0.8),
+            idle_alt: Color::rgb(0.8, 0.2, 0.2).into(),
+            ..default()
+        })
+        .idle(0.5, Ease::InOutExpo, delay, 0., AnimationLoop::Continous);
+
+    builder
+        .animated()
+        .width(AnimatedVals {
+            idle: Val::Px(NODE_SIZE / 2.),
+            idle_alt: Val::Px(NODE_SIZE).into(),
+            ..default()
+        })
+        .idle(0.75, Ease::InOutCubic, delay, 0., AnimationLoop::Continous);
+
+    builder
+        .animated()
+        .height(AnimatedVals {
+            idle: Val::Px(NODE_SIZE),
+            idle_alt: Val::Px(NODE_SIZE / 2.).into(),
+            ..default()
+        })
+        .idle(0.75, Ease::InOutCubic, delay, 0., AnimationLoop::Continous);
+
+    builder.into()
+}
@@ -0,0 +1,76 @@
+//! Measures the cost of keeping hierarchy pseudo states up to date while list rows churn.
+//!
+//! Runs headless: spawns 10,000 tagged rows split across several lists, then replaces a few
+//! rows of a single list every frame, like a virtualized list scrolling, and logs the average
+//! update time.
+use std::time::{Duration, Instant};
+
+use bevy::{log::LogPlugin, prelude::*};
+
+use sickle_ui::theme::pseudo_state::HierarchyToPseudoState;
+
+const ROW_COUNT: usize = 10_000;
+const LIST_COUNT: usize = 10;
+const ROWS_REPLACED_PER_FRAME: usize = 20;
+const FRAMES: u32 = 600;
+
+fn main() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, LogPlugin::default(), HierarchyPlugin))
+        .add_plugins(HierarchyToPseudoState::<ListRow>::new())
+        .add_systems(Startup, setup)
+        .add_systems(Update, churn_rows);
+
+    // Initial spawn and the first full pass
+    app.update();
+    app.update();
+
+    let mut total = Duration::ZERO;
+    for _ in 0..FRAMES {
+        let start = Instant::now();
+        app.update();
+        total += start.elapsed();
+    }
+
+    info!(
+        "{} rows, {} replaced per frame: {:.3} ms/frame over {} frames",
+        ROW_COUNT,
+        ROWS_REPLACED_PER_FRAME,
+        total.as_secs_f64() * 1000. / FRAMES as f64,
+        FRAMES,
+    );
+}
+
+#[derive(Component)]
+struct ListRow;
+
+#[derive(Component)]
+struct List;
+
+fn setup(mut commands: Commands) {
+    for _ in 0..LIST_COUNT {
+        commands
+            .spawn((SpatialBundle::default(), List))
+            .with_children(|list| {
+                for _ in 0..ROW_COUNT / LIST_COUNT {
+                    list.spawn((SpatialBundle::default(), ListRow));
+                }
+            });
+    }
+}
+
+fn churn_rows(q_lists: Query<(Entity, &Children), With<List>>, mut commands: Commands) {
+    let Some((list, rows)) = q_lists.iter().next() else {
+        return;
+    };
+
+    for row in rows.iter().take(ROWS_REPLACED_PER_FRAME) {
+        commands.entity(*row).despawn_recursive();
+    }
+
+    commands.entity(list).with_children(|list| {
+        for _ in 0..ROWS_REPLACED_PER_FRAME {
+            list.spawn((SpatialBundle::default(), ListRow));
+        }
+    });
+}
@@ -274,7 +274,7 @@ fn setup(
                         leading_icon: icons.exit_to_app,
                         ..default()
                     })
-                    .insert(ExitAppButton);
+                    .insert((ExitAppButton, Tooltip::text("Quit the editor")));
                 },
             );
             bar.menu(
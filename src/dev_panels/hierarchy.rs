@@ -8,7 +8,7 @@ use crate::widgets::{
         foldable::{Foldable, UiFoldableExt},
         panel::UiPanelExt,
         row::UiRowExt,
-        scroll_view::UiScrollViewExt,
+        scroll_view::{ManageScrollViewExt, ScrollAlignment, UiScrollViewExt},
         sized_zone::{SizedZoneConfig, UiSizedZoneExt},
     },
     menus::menu_item::{MenuItem, MenuItemConfig, UiMenuItemExt},
@@ -35,6 +35,7 @@ impl Plugin for HierarchyTreeViewPlugin {
                 update_hierarchy_on_foldable_change,
                 update_hierarchy_selection,
                 update_hierarchy_node_style,
+                scroll_to_selected_hierarchy_node,
                 update_entity_component_list,
             )
                 .chain()
@@ -189,6 +190,29 @@ fn update_hierarchy_node_style(
     }
 }
 
+fn scroll_to_selected_hierarchy_node(
+    q_hierarchies: Query<(Entity, &HierarchyContainer), Changed<HierarchyContainer>>,
+    q_hierarchy_nodes: Query<(Entity, &HierarchyNode)>,
+    mut commands: Commands,
+) {
+    for (entity, hierarchy) in &q_hierarchies {
+        let Some(selected) = hierarchy.selected else {
+            continue;
+        };
+
+        let Some((menu_item, _)) = q_hierarchy_nodes
+            .iter()
+            .find(|(_, node)| node.hierarchy == entity && node.entity == selected)
+        else {
+            continue;
+        };
+
+        commands
+            .entity(hierarchy.scroll_view)
+            .scroll_into_view(menu_item, ScrollAlignment::Nearest);
+    }
+}
+
 fn spawn_hierarchy_level(
     hierarchy: Entity,
     entity: Entity,
@@ -244,6 +268,7 @@ pub struct HierarchyContainer {
     root: Entity,
     selected: Option<Entity>,
     component_list: Entity,
+    scroll_view: Entity,
 }
 
 pub trait UiHierarchyExt {
@@ -254,6 +279,7 @@ impl UiHierarchyExt for UiBuilder<'_, Entity> {
     fn hierarchy_for(&mut self, root_entity: Entity) -> UiBuilder<Entity> {
         self.column(|column| {
             column.style().width(Val::Percent(100.));
+            let mut scroll_view_id = Entity::PLACEHOLDER;
             let main_zone = column
                 .sized_zone(
                     SizedZoneConfig {
@@ -285,21 +311,23 @@ impl UiHierarchyExt for UiBuilder<'_, Entity> {
                                 .margin(UiRect::bottom(Val::Px(10.)))
                                 .border_color(Color::ANTIQUE_WHITE);
 
-                            panel.scroll_view(None, |scroll_view| {
-                                let node_container = scroll_view
-                                    .column(|_| {})
-                                    .insert(HierarchyNodeContainer {
-                                        hierarchy: hierarchy_id,
-                                    })
-                                    .id();
-
-                                scroll_view.commands().entity(refresh_button).insert(
-                                    RefreshHierarchyButton {
-                                        hierarchy: hierarchy_id,
-                                        container: node_container,
-                                    },
-                                );
-                            });
+                            scroll_view_id = panel
+                                .scroll_view(None, |scroll_view| {
+                                    let node_container = scroll_view
+                                        .column(|_| {})
+                                        .insert(HierarchyNodeContainer {
+                                            hierarchy: hierarchy_id,
+                                        })
+                                        .id();
+
+                                    scroll_view.commands().entity(refresh_button).insert(
+                                        RefreshHierarchyButton {
+                                            hierarchy: hierarchy_id,
+                                            container: node_container,
+                                        },
+                                    );
+                                })
+                                .id();
                         });
                     },
                 )
@@ -322,6 +350,7 @@ impl UiHierarchyExt for UiBuilder<'_, Entity> {
                     root: root_entity,
                     selected: None,
                     component_list,
+                    scroll_view: scroll_view_id,
                 },
             ));
         })
@@ -11,10 +11,19 @@ use assets::BuiltInAssetsPlugin;
 use drag_interaction::DragInteractionPlugin;
 use drop_interaction::DropInteractionPlugin;
 use flux_interaction::FluxInteractionPlugin;
+use focus_interaction::FocusInteractionPlugin;
 use hierarchy_delay::HierarchyDelayPlugin;
+use inherited_text_style::InheritedTextStylePlugin;
 use interactions::InteractionsPlugin;
+use layout_animation::LayoutAnimationPlugin;
+use long_press_interaction::LongPressInteractionPlugin;
+use navigation_interaction::NavigationInteractionPlugin;
+use node_shape::NodeShapePlugin;
 use scroll_interaction::ScrollInteractionPlugin;
 use theme::ThemePlugin;
+use ui_style::UiStylePlugin;
+use ui_time::UiTimePlugin;
+use ui_transform::UiTransformPlugin;
 use widgets::WidgetsPlugin;
 
 pub use sickle_macros::*;
@@ -24,7 +33,7 @@ pub use sickle_ui_scaffold::*;
 pub mod prelude {
     pub use super::widgets::prelude::*;
     pub use sickle_macros::*;
-    pub use sickle_math::*;
+    pub use sickle_math::{lerp::Lerp, *};
     pub use sickle_ui_scaffold::prelude::*;
 }
 
@@ -41,11 +50,20 @@ impl Plugin for SickleUiPlugin {
             DragInteractionPlugin,
             DropInteractionPlugin,
             HierarchyDelayPlugin,
-            FluxInteractionPlugin,
+            (
+                FluxInteractionPlugin,
+                FocusInteractionPlugin,
+                NavigationInteractionPlugin,
+                LongPressInteractionPlugin,
+            ),
+            InheritedTextStylePlugin,
             InteractionsPlugin,
+            LayoutAnimationPlugin,
+            NodeShapePlugin,
             ScrollInteractionPlugin,
             WidgetsPlugin,
             ThemePlugin,
+            (UiStylePlugin, UiTimePlugin, UiTransformPlugin),
         ));
     }
 }
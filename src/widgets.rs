@@ -16,6 +16,7 @@ use self::{
     layout::scroll_view::ScrollViewPlugin,
     layout::sized_zone::SizedZonePlugin,
     layout::tab_container::TabContainerPlugin,
+    layout::tooltip::TooltipPlugin,
     menus::context_menu::ContextMenuPlugin,
     menus::menu::MenuPlugin,
     menus::menu_bar::MenuBarPlugin,
@@ -44,9 +45,13 @@ pub mod prelude {
         layout::panel::UiPanelExt,
         layout::resize_handles::{ResizeHandle, ResizeHandles, UiResizeHandlesExt},
         layout::row::UiRowExt,
-        layout::scroll_view::{ScrollViewLayoutUpdate, UiScrollViewExt},
+        layout::scroll_view::{
+            ManageScrollViewExt, ScrollAlignment, ScrollView, ScrollViewChanged, ScrollViewConfig,
+            ScrollViewLayoutUpdate, UiScrollViewExt,
+        },
         layout::sized_zone::{SizedZoneConfig, SizedZonePreUpdate, UiSizedZoneExt},
         layout::tab_container::{TabContainerUpdate, UiTabContainerExt, UiTabContainerSubExt},
+        layout::tooltip::{Tooltip, TooltipPlacement, TooltipUpdate},
         menus::context_menu::{
             ContextMenuGenerator, ContextMenuUpdate, ReflectContextMenuGenerator, UiContextMenuExt,
         },
@@ -94,6 +99,7 @@ impl Plugin for WidgetsPlugin {
                 SubmenuPlugin,
                 TabContainerPlugin,
                 ToggleMenuItemPlugin,
+                TooltipPlugin,
             ));
     }
 }
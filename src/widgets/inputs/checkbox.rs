@@ -107,20 +107,34 @@ impl Checkbox {
         let base_theme = PseudoTheme::deferred(None, Checkbox::primary_style);
         let checked_theme =
             PseudoTheme::deferred(vec![PseudoState::Checked], Checkbox::checked_style);
-        Theme::new(vec![base_theme, checked_theme])
+        let focused_theme =
+            PseudoTheme::deferred(vec![PseudoState::Focused], ThemeData::focus_ring);
+        Theme::new(vec![base_theme, checked_theme, focused_theme])
     }
 
-    // TODO: bevy 0.14: Add border radius
     fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
         let theme_spacing = theme_data.spacing;
         let colors = theme_data.colors();
+        let font = theme_data
+            .text
+            .get(FontStyle::Body, FontScale::Medium, FontType::Regular);
+
+        ThemeData::no_focus_ring(style_builder);
 
         style_builder
             .height(Val::Px(theme_spacing.inputs.checkbox.line_height))
             .justify_content(JustifyContent::Start)
             .align_items(AlignItems::Center)
             .margin(UiRect::horizontal(Val::Px(theme_spacing.gaps.small)))
-            .background_color(Color::NONE);
+            .background_color(Color::NONE)
+            .inherited_sized_font(font)
+            .animated()
+            .inherited_font_color(AnimatedVals {
+                idle: colors.on(On::SurfaceVariant),
+                hover: colors.on(On::Surface).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
 
         style_builder
             .switch_target(Checkbox::CHECKMARK_BACKGROUND)
@@ -132,9 +146,10 @@ impl Checkbox {
             .border(UiRect::all(Val::Px(
                 theme_spacing.inputs.checkbox.border_size,
             )))
-            .background_color(Color::NONE)
+            .corner_radius(theme_data.shapes.extra_small / 2.)
+            .shape_border_width(theme_spacing.inputs.checkbox.border_size)
             .animated()
-            .border_color(AnimatedVals {
+            .shape_border_color(AnimatedVals {
                 idle: colors.on(On::SurfaceVariant),
                 hover: colors.on(On::Surface).into(),
                 ..default()
@@ -149,9 +164,6 @@ impl Checkbox {
                 theme_spacing.inputs.checkbox.checkmark_size,
             ));
 
-        let font = theme_data
-            .text
-            .get(FontStyle::Body, FontScale::Medium, FontType::Regular);
         style_builder
             .switch_target(Checkbox::LABEL)
             .margin(UiRect::px(
@@ -159,15 +171,7 @@ impl Checkbox {
                 theme_spacing.gaps.medium,
                 0.,
                 0.,
-            ))
-            .sized_font(font)
-            .animated()
-            .font_color(AnimatedVals {
-                idle: colors.on(On::SurfaceVariant),
-                hover: colors.on(On::Surface).into(),
-                ..default()
-            })
-            .copy_from(theme_data.interaction_animation);
+            ));
     }
 
     fn checked_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
@@ -187,7 +191,7 @@ impl Checkbox {
         style_builder
             .switch_target(Checkbox::CHECKMARK_BACKGROUND)
             .animated()
-            .background_color(AnimatedVals {
+            .shape_color(AnimatedVals {
                 idle: colors.container(Container::Primary),
                 enter_from: Some(Color::NONE),
                 ..default()
@@ -220,6 +224,7 @@ impl Checkbox {
             Name::new(name),
             ButtonBundle::default(),
             TrackedInteraction::default(),
+            Focusable::default(),
         )
     }
 
@@ -257,8 +262,11 @@ pub trait UiCheckboxExt {
 impl UiCheckboxExt for UiBuilder<'_, Entity> {
     /// A simple checkbox with an optional label.
     ///
+    /// The checkbox is focusable, the confirm action toggles it while focused.
+    ///
     /// ### PseudoState usage
     /// - `PseudoState::Checked`, when the checkbox is in a checked state
+    /// - `PseudoState::Focused`, when the checkbox has keyboard focus
     fn checkbox(&mut self, label: impl Into<Option<String>>, checked: bool) -> UiBuilder<Entity> {
         let mut checkbox = Checkbox {
             checked,
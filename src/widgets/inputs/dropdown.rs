@@ -1,6 +1,6 @@
 use std::collections::VecDeque;
 
-use bevy::{prelude::*, ui::FocusPolicy};
+use bevy::{prelude::*, text::BreakLineOn, ui::FocusPolicy};
 
 use sickle_ui_scaffold::prelude::*;
 
@@ -27,6 +27,9 @@ impl Plugin for DropdownPlugin {
                 handle_option_press,
                 update_dropdown_label,
                 handle_click_or_touch.after(FluxInteractionUpdate),
+                handle_navigation
+                    .after(NavigationUpdate)
+                    .after(FocusableUpdate),
                 update_drowdown_pseudo_state,
                 update_dropdown_panel_visibility.before(ScrollViewLayoutUpdate),
             )
@@ -68,14 +71,15 @@ fn update_dropdown_label(
 fn handle_click_or_touch(
     r_mouse: Res<ButtonInput<MouseButton>>,
     r_touches: Res<Touches>,
-    mut q_dropdowns: Query<(Entity, &mut Dropdown, &FluxInteraction)>,
+    mut q_dropdowns: Query<(Entity, &mut Dropdown, Ref<FluxInteraction>)>,
 ) {
     if r_mouse.any_just_released([MouseButton::Left, MouseButton::Middle, MouseButton::Right])
         || r_touches.any_just_released()
     {
         let mut open: Option<Entity> = None;
         for (entity, _, interaction) in &mut q_dropdowns {
-            if *interaction == FluxInteraction::Released {
+            // Confirming through navigation also leaves a release behind
+            if interaction.is_changed() && *interaction == FluxInteraction::Released {
                 open = entity.into();
                 break;
             }
@@ -95,6 +99,98 @@ fn handle_click_or_touch(
     }
 }
 
+fn handle_navigation(
+    mut r_focus: ResMut<FocusManager>,
+    mut events: EventReader<NavigationEvent>,
+    q_options: Query<(Entity, &DropdownOption)>,
+    q_parents: Query<&Parent>,
+    mut q_dropdowns: Query<(Entity, &mut Dropdown, &DropdownOptions)>,
+    mut commands: Commands,
+) {
+    for event in events.read() {
+        let Some(target) = event.target else {
+            continue;
+        };
+
+        match event.action {
+            NavigationAction::Move(direction) => {
+                // Captured by the closed dropdown, open panels use spatial navigation
+                let Ok((_, mut dropdown, options)) = q_dropdowns.get_mut(target) else {
+                    continue;
+                };
+                if dropdown.is_open || options.0.is_empty() {
+                    continue;
+                }
+
+                let step: isize = match direction {
+                    NavigationDirection::Up => -1,
+                    NavigationDirection::Down => 1,
+                    _ => continue,
+                };
+                let last = options.0.len() - 1;
+                let value = match dropdown.value {
+                    Some(value) => value.saturating_add_signed(step).min(last),
+                    None => 0,
+                };
+                dropdown.set_value(value);
+            }
+            NavigationAction::ConfirmReleased => {
+                if let Ok((_, mut dropdown, _)) = q_dropdowns.get_mut(target) {
+                    dropdown.is_open = !dropdown.is_open;
+                    if dropdown.is_open {
+                        // Start from the selected option
+                        let selected = q_options.iter().find(|(_, option)| {
+                            option.dropdown == target
+                                && Some(option.option) == dropdown.value.or(Some(0))
+                        });
+                        if let Some((option, _)) = selected {
+                            r_focus.focus(option);
+                        }
+                    }
+                } else if let Ok((_, option)) = q_options.get(target) {
+                    // The option itself is selected on release by `handle_option_press`
+                    if let Ok((_, mut dropdown, _)) = q_dropdowns.get_mut(option.dropdown) {
+                        dropdown.is_open = false;
+                    }
+                }
+            }
+            NavigationAction::Back => {
+                if let Ok((_, mut dropdown, _)) = q_dropdowns.get_mut(target) {
+                    dropdown.is_open = false;
+                }
+            }
+            _ => (),
+        }
+    }
+
+    for (entity, mut dropdown, _) in &mut q_dropdowns {
+        if !dropdown.is_changed() && !r_focus.is_changed() {
+            continue;
+        }
+
+        let focused_within = r_focus.focused().is_some_and(|focused| {
+            focused == entity || q_parents.iter_ancestors(focused).any(|e| e == entity)
+        });
+
+        if dropdown.is_open {
+            if focused_within {
+                commands.entity(entity).insert(BackNavigationHandler);
+            } else {
+                // Focus moved away, e.g. with Tab
+                dropdown.is_open = false;
+            }
+        }
+
+        if !dropdown.is_open {
+            commands.entity(entity).remove::<BackNavigationHandler>();
+            if focused_within && !r_focus.is_focused(entity) {
+                let origin = r_focus.origin();
+                r_focus.focus_with(entity, origin);
+            }
+        }
+    }
+}
+
 fn handle_option_press(
     q_options: Query<(&DropdownOption, &FluxInteraction), Changed<FluxInteraction>>,
     mut q_dropdown: Query<&mut Dropdown>,
@@ -224,8 +320,10 @@ impl DropdownOption {
 
     pub fn theme() -> Theme<DropdownOption> {
         let base_theme = PseudoTheme::deferred(None, DropdownOption::primary_style);
+        let focused_theme =
+            PseudoTheme::deferred(vec![PseudoState::Focused], DropdownOption::focused_style);
 
-        Theme::new(vec![base_theme])
+        Theme::new(vec![base_theme, focused_theme])
     }
 
     fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
@@ -235,6 +333,8 @@ impl DropdownOption {
             .text
             .get(FontStyle::Body, FontScale::Medium, FontType::Regular);
 
+        ThemeData::no_focus_ring(style_builder);
+
         style_builder
             .align_items(AlignItems::Center)
             .min_width(Val::Percent(100.))
@@ -253,9 +353,17 @@ impl DropdownOption {
 
         style_builder
             .switch_target(DropdownOption::LABEL)
+            .line_break(BreakLineOn::NoWrap)
             .sized_font(font)
             .font_color(colors.on(On::Surface));
     }
+
+    fn focused_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let colors = theme_data.colors();
+
+        style_builder.background_color(colors.container(Container::SurfaceHighest));
+        ThemeData::inset_focus_ring(style_builder, theme_data);
+    }
 }
 
 #[derive(Component, Debug, Reflect)]
@@ -352,17 +460,22 @@ impl Dropdown {
     pub fn theme() -> Theme<Dropdown> {
         let base_theme = PseudoTheme::deferred(None, Dropdown::primary_style);
         let open_theme = PseudoTheme::deferred_world(vec![PseudoState::Open], Dropdown::open_style);
+        let focused_theme =
+            PseudoTheme::deferred(vec![PseudoState::Focused], ThemeData::focus_ring);
 
-        Theme::new(vec![base_theme, open_theme])
+        Theme::new(vec![base_theme, open_theme, focused_theme])
     }
 
     fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
         let theme_spacing = theme_data.spacing;
         let colors = theme_data.colors();
+        let elevation = theme_data.elevation.level(Elevation::Level2);
         let font = theme_data
             .text
             .get(FontStyle::Body, FontScale::Medium, FontType::Regular);
 
+        ThemeData::no_focus_ring(style_builder);
+
         style_builder
             .align_self(AlignSelf::Start)
             .align_items(AlignItems::Center)
@@ -382,6 +495,7 @@ impl Dropdown {
 
         style_builder
             .switch_target(Dropdown::LABEL)
+            .line_break(BreakLineOn::NoWrap)
             .sized_font(font)
             .animated()
             .font_color(AnimatedVals {
@@ -414,8 +528,13 @@ impl Dropdown {
             .position_type(PositionType::Absolute)
             .z_index(ZIndex::Global(DROPDOWN_PANEL_Z_INDEX as i32))
             .border(UiRect::all(Val::Px(theme_spacing.gaps.tiny)))
-            .border_color(colors.accent(Accent::Shadow))
-            .background_color(colors.container(Container::SurfaceMid))
+            .corner_radius(theme_data.shapes.extra_small)
+            .shape_color(colors.container(Container::SurfaceMid))
+            .shape_border_width(theme_spacing.gaps.tiny)
+            .shape_border_color(colors.accent(Accent::Shadow))
+            .shadow_color(elevation.shadow_color(colors.accent(Accent::Shadow)))
+            .shadow_offset(elevation.offset)
+            .shadow_blur(elevation.blur)
             .top(Val::Px(theme_spacing.areas.medium))
             .min_width(Val::Percent(100.))
             .max_height(Val::Px(theme_spacing.areas.extra_large));
@@ -602,6 +721,8 @@ impl Dropdown {
                 ..default()
             },
             TrackedInteraction::default(),
+            Focusable::default(),
+            NavigationNeighbors::vertical(NavigationTarget::Captured),
             LockedStyleAttributes::from_vec(vec![
                 LockableStyleAttribute::FlexDirection,
                 LockableStyleAttribute::Overflow,
@@ -630,6 +751,7 @@ impl Dropdown {
                 ..default()
             },
             TrackedInteraction::default(),
+            Focusable::with_tab_index(-1),
             LockedStyleAttributes::lock(LockableStyleAttribute::FocusPolicy),
         )
     }
@@ -646,8 +768,13 @@ pub trait UiDropdownExt {
 impl UiDropdownExt for UiBuilder<'_, Entity> {
     /// A simple dropdown with options.
     /// 
+    /// The dropdown is focusable. The confirm action opens the options panel and focuses the
+    /// selected option, confirming an option selects it and closes the panel, and the back action
+    /// closes it. While closed, the up and down arrows select the previous or next option.
+    ///
     /// ### PseudoState usage
     /// - `PseudoState::Open`, when the options panel should be visible
+    /// - `PseudoState::Focused`, when the dropdown has keyboard focus
     fn dropdown(
         &mut self,
         options: Vec<impl Into<String>>,
@@ -20,6 +20,7 @@ impl Plugin for RadioGroupPlugin {
             Update,
             (
                 toggle_radio_button,
+                select_radio_button_on_navigation.after(NavigationUpdate),
                 update_radio_group_buttons,
                 update_radio_button,
             ),
@@ -64,6 +65,49 @@ fn toggle_radio_button(
     }
 }
 
+fn select_radio_button_on_navigation(
+    mut r_focus: ResMut<FocusManager>,
+    mut events: EventReader<NavigationEvent>,
+    q_radio_buttons: Query<(&RadioButton, &NavigationNeighbors)>,
+    mut q_group: Query<(&mut RadioGroup, &Children)>,
+) {
+    for event in events.read() {
+        let (Some(target), NavigationAction::Move(direction)) = (event.target, event.action) else {
+            continue;
+        };
+        let Ok((radio_button, neighbors)) = q_radio_buttons.get(target) else {
+            continue;
+        };
+
+        // The first and last buttons let focus leave the group
+        if neighbors.get(direction) != NavigationTarget::Captured {
+            continue;
+        }
+
+        let Ok((mut radio_group, children)) = q_group.get_mut(radio_button.group) else {
+            continue;
+        };
+
+        let step: isize = match direction {
+            NavigationDirection::Up | NavigationDirection::Left => -1,
+            NavigationDirection::Down | NavigationDirection::Right => 1,
+        };
+        let Some(index) = radio_button.index.checked_add_signed(step) else {
+            continue;
+        };
+        let next = children.iter().find(|child| {
+            q_radio_buttons
+                .get(**child)
+                .is_ok_and(|(button, _)| button.index == index)
+        });
+
+        if let Some(next) = next {
+            radio_group.select(index);
+            r_focus.focus(*next);
+        }
+    }
+}
+
 fn update_radio_group_buttons(
     mut q_radio_buttons: Query<(&RadioGroup, &Children), Changed<RadioGroup>>,
     mut q_radio_button: Query<&mut RadioButton>,
@@ -213,7 +257,9 @@ impl RadioButton {
         let base_theme = PseudoTheme::deferred(None, RadioButton::primary_style);
         let checked_theme =
             PseudoTheme::deferred(vec![PseudoState::Checked], RadioButton::checked_style);
-        Theme::new(vec![base_theme, checked_theme])
+        let focused_theme =
+            PseudoTheme::deferred(vec![PseudoState::Focused], ThemeData::focus_ring);
+        Theme::new(vec![base_theme, checked_theme, focused_theme])
     }
 
     fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
@@ -227,6 +273,8 @@ impl RadioButton {
             .margin(UiRect::horizontal(Val::Px(theme_spacing.gaps.small)))
             .background_color(Color::NONE);
 
+        ThemeData::no_focus_ring(style_builder);
+
         style_builder
             .switch_target(RadioButton::RADIOMARK_BACKGROUND)
             .justify_content(JustifyContent::Center)
@@ -309,14 +357,37 @@ impl RadioButton {
             .copy_from(theme_data.enter_animation);
     }
 
-    fn button(name: String) -> impl Bundle {
+    fn button(name: String, neighbors: NavigationNeighbors) -> impl Bundle {
         (
             Name::new(name),
             ButtonBundle::default(),
             TrackedInteraction::default(),
+            Focusable::default(),
+            neighbors,
         )
     }
 
+    /// Arrows move the selection within the group, past the first and last button they move focus
+    fn navigation_neighbors(index: usize, count: usize) -> NavigationNeighbors {
+        let mut neighbors = NavigationNeighbors {
+            up: NavigationTarget::Captured,
+            down: NavigationTarget::Captured,
+            left: NavigationTarget::Captured,
+            right: NavigationTarget::Captured,
+        };
+
+        if index == 0 {
+            neighbors.up = NavigationTarget::Auto;
+            neighbors.left = NavigationTarget::Auto;
+        }
+        if index + 1 >= count {
+            neighbors.down = NavigationTarget::Auto;
+            neighbors.right = NavigationTarget::Auto;
+        }
+
+        neighbors
+    }
+
     fn radio_mark_background() -> impl Bundle {
         (
             Name::new("Radiomark Background"),
@@ -357,8 +428,13 @@ pub trait UiRadioGroupExt {
 impl UiRadioGroupExt for UiBuilder<'_, Entity> {
     /// A simple radio group with options. Optionally, the radio group can be "unselected"
     /// 
+    /// Radio buttons are focusable, the confirm action selects the focused option. Arrows move
+    /// the selection (and focus) to the previous or next option, and out of the group past the
+    /// first and last option.
+    ///
     /// ### PseudoState usage
     /// - `PseudoState::Checked` is added to the currently selected `RadioButton` entity
+    /// - `PseudoState::Focused` is added to the `RadioButton` entity with keyboard focus
     fn radio_group(
         &mut self,
         options: Vec<impl Into<String>>,
@@ -373,6 +449,7 @@ impl UiRadioGroupExt for UiBuilder<'_, Entity> {
         ));
 
         let mut index = 0;
+        let count = options.len();
         let group = radio_group.id();
         for option in options {
             let label = option.into();
@@ -385,8 +462,9 @@ impl UiRadioGroupExt for UiBuilder<'_, Entity> {
                 ..default()
             };
 
+            let neighbors = RadioButton::navigation_neighbors(index, count);
             radio_group
-                .container(RadioButton::button(name), |button| {
+                .container(RadioButton::button(name, neighbors), |button| {
                     radio_button.radiomark_background = button
                         .container(RadioButton::radio_mark_background(), |radio_mark_bg| {
                             radio_button.radiomark =
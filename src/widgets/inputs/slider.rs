@@ -7,6 +7,8 @@ use crate::widgets::layout::{
     label::{LabelConfig, UiLabelExt},
 };
 
+const NAVIGATION_STEP: f32 = 0.05;
+
 pub struct SliderPlugin;
 
 impl Plugin for SliderPlugin {
@@ -17,6 +19,9 @@ impl Plugin for SliderPlugin {
                 (
                     update_slider_on_scroll.after(ScrollableUpdate),
                     update_slider_on_drag.after(DraggableUpdate),
+                    update_slider_on_navigation
+                        .after(NavigationUpdate)
+                        .after(FocusableUpdate),
                     update_slider_handle,
                     update_slider_readout,
                 )
@@ -68,12 +73,27 @@ fn update_slider_on_drag(
     q_draggable: Query<(&Draggable, &SliderDragHandle, &Node), Changed<Draggable>>,
     q_node: Query<&Node>,
     mut q_slider: Query<&mut Slider>,
+    mut commands: Commands,
 ) {
     for (draggable, handle, node) in &q_draggable {
         let Ok(mut slider) = q_slider.get_mut(handle.slider) else {
             continue;
         };
 
+        match draggable.state {
+            DragState::DragStart => {
+                commands
+                    .entity(handle.slider)
+                    .add_pseudo_state(PseudoState::Dragged);
+            }
+            DragState::DragEnd | DragState::DragCanceled => {
+                commands
+                    .entity(handle.slider)
+                    .remove_pseudo_state(PseudoState::Dragged);
+            }
+            _ => (),
+        }
+
         if draggable.state == DragState::Inactive || draggable.state == DragState::MaybeDragged {
             continue;
         }
@@ -118,6 +138,53 @@ fn update_slider_on_drag(
     }
 }
 
+fn update_slider_on_navigation(
+    r_keys: Res<ButtonInput<KeyCode>>,
+    r_navigation: Res<NavigationConfig>,
+    r_focus: Res<FocusManager>,
+    mut events: EventReader<NavigationEvent>,
+    mut q_slider: Query<(&mut Slider, &NavigationNeighbors)>,
+) {
+    let mut changes: Vec<(Entity, f32)> = Vec::new();
+    for event in events.read() {
+        let (Some(target), NavigationAction::Move(direction)) = (event.target, event.action) else {
+            continue;
+        };
+        let Ok((_, neighbors)) = q_slider.get(target) else {
+            continue;
+        };
+
+        if neighbors.get(direction) != NavigationTarget::Captured {
+            continue;
+        }
+
+        let step = match direction {
+            NavigationDirection::Up | NavigationDirection::Right => NAVIGATION_STEP,
+            NavigationDirection::Down | NavigationDirection::Left => -NAVIGATION_STEP,
+        };
+        changes.push((target, step));
+    }
+
+    if let (true, Some(focused)) = (r_navigation.keyboard, r_focus.focus_visible()) {
+        if r_keys.just_pressed(KeyCode::Home) {
+            changes.push((focused, f32::NEG_INFINITY));
+        } else if r_keys.just_pressed(KeyCode::End) {
+            changes.push((focused, f32::INFINITY));
+        }
+    }
+
+    for (entity, step) in changes {
+        let Ok((mut slider, _)) = q_slider.get_mut(entity) else {
+            continue;
+        };
+
+        let ratio = (slider.ratio + step).clamp(0., 1.);
+        if slider.ratio != ratio {
+            slider.ratio = ratio;
+        }
+    }
+}
+
 fn update_slider_handle(
     q_slider: Query<&Slider, Or<(Changed<Slider>, Changed<Node>)>>,
     q_node: Query<&Node>,
@@ -381,7 +448,11 @@ impl Slider {
 
     pub fn theme() -> Theme<Slider> {
         let base_theme = PseudoTheme::deferred_context(None, Slider::primary_style);
-        Theme::new(vec![base_theme])
+        let focused_theme =
+            PseudoTheme::deferred(vec![PseudoState::Focused], ThemeData::inset_focus_ring);
+        let dragged_theme =
+            PseudoTheme::deferred(vec![PseudoState::Dragged], Slider::dragged_style);
+        Theme::new(vec![base_theme, focused_theme, dragged_theme])
     }
 
     // TODO: bevy 0.14: Redesign handle style with rounded borders
@@ -476,6 +547,8 @@ impl Slider {
 
         style_builder.reset_context();
 
+        ThemeData::no_focus_ring(style_builder);
+
         style_builder
             .switch_target(Slider::LABEL)
             .sized_font(font.clone())
@@ -535,8 +608,36 @@ impl Slider {
             .copy_from(theme_data.interaction_animation);
     }
 
-    fn container(name: String) -> impl Bundle {
-        (Name::new(name), NodeBundle::default())
+    fn dragged_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let colors = theme_data.colors();
+
+        style_builder
+            .switch_context(Slider::HANDLE, None)
+            .animated()
+            .background_color(AnimatedVals {
+                idle: colors.accent(Accent::Primary),
+                hover: colors.container(Container::Primary).into(),
+                press: colors
+                    .accent(Accent::Primary)
+                    .with_a(theme_data.dragged_opacity)
+                    .into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+    }
+
+    fn container(name: String, axis: SliderAxis) -> impl Bundle {
+        let neighbors = match axis {
+            SliderAxis::Horizontal => NavigationNeighbors::horizontal(NavigationTarget::Captured),
+            SliderAxis::Vertical => NavigationNeighbors::vertical(NavigationTarget::Captured),
+        };
+
+        (
+            Name::new(name),
+            NodeBundle::default(),
+            Focusable::default(),
+            neighbors,
+        )
     }
 
     fn bar_container() -> impl Bundle {
@@ -552,13 +653,19 @@ impl Slider {
         (Name::new("Slider Bar"), NodeBundle::default())
     }
 
-    fn handle(slider: Entity) -> impl Bundle {
+    fn handle(slider: Entity, axis: SliderAxis) -> impl Bundle {
+        let drag_axis = match axis {
+            SliderAxis::Horizontal => DragAxis::Horizontal,
+            SliderAxis::Vertical => DragAxis::Vertical,
+        };
+
         (
             Name::new("Handle"),
             ButtonBundle::default(),
             TrackedInteraction::default(),
             SliderDragHandle { slider },
             Draggable::default(),
+            DraggableConfig::with_axis(drag_axis),
             RelativeCursorPosition::default(),
             Scrollable::default(),
         )
@@ -574,6 +681,15 @@ pub trait UiSliderExt {
 }
 
 impl UiSliderExt for UiBuilder<'_, Entity> {
+    /// A slider with an optional label and readout.
+    ///
+    /// The slider is focusable. While focused, moving along its axis (arrow keys, D-pad or stick)
+    /// changes it by 5% of its range, Home and End move it to its minimum and maximum.
+    /// Moves across its axis navigate to other widgets.
+    ///
+    /// ### PseudoState usage
+    /// - `PseudoState::Focused`, when the slider has keyboard focus
+    /// - `PseudoState::Dragged`, while the handle is dragged
     fn slider(&mut self, config: SliderConfig) -> UiBuilder<Entity> {
         let mut slider = Slider {
             ratio: (config.initial_value - config.min) / (config.max + (0. - config.min)),
@@ -591,7 +707,7 @@ impl UiSliderExt for UiBuilder<'_, Entity> {
             false => "Slider".into(),
         };
 
-        let mut input = self.container(Slider::container(name), |container| {
+        let mut input = self.container(Slider::container(name, config.axis), |container| {
             let input_id = container.id();
 
             slider.label = container.label(LabelConfig { label, ..default() }).id();
@@ -601,7 +717,8 @@ impl UiSliderExt for UiBuilder<'_, Entity> {
                     |bar_container| {
                         slider.bar = bar_container
                             .container(Slider::bar(), |bar| {
-                                slider.handle = bar.spawn(Slider::handle(input_id)).id();
+                                slider.handle =
+                                    bar.spawn(Slider::handle(input_id, config.axis)).id();
                             })
                             .id();
                     },
@@ -11,3 +11,4 @@ pub mod row;
 pub mod scroll_view;
 pub mod sized_zone;
 pub mod tab_container;
+pub mod tooltip;
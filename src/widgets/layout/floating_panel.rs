@@ -34,6 +34,7 @@ impl Plugin for FloatingPanelPlugin {
                     index_floating_panels.run_if(panel_added),
                     process_panel_close_pressed,
                     process_panel_fold_pressed,
+                    fold_panel_on_title_double_click,
                     update_panel_size_on_resize,
                     update_panel_on_title_drag,
                     handle_window_resize.run_if(window_resized),
@@ -133,6 +134,23 @@ fn process_panel_fold_pressed(
     }
 }
 
+fn fold_panel_on_title_double_click(
+    q_titles: Query<(&FloatingPanelTitle, &FluxInteraction, &ClickCount), Changed<FluxInteraction>>,
+    mut q_panel_configs: Query<&mut FloatingPanelConfig>,
+) {
+    for (title, interaction, click_count) in &q_titles {
+        if *interaction == FluxInteraction::Released && click_count.is_double() {
+            let Ok(mut config) = q_panel_configs.get_mut(title.panel) else {
+                continue;
+            };
+
+            if config.foldable {
+                config.folded = !config.folded;
+            }
+        }
+    }
+}
+
 fn update_panel_size_on_resize(
     q_draggable: Query<(&Draggable, &ResizeHandle, &FloatingPanelResizeHandle), Changed<Draggable>>,
     mut q_panels: Query<&mut FloatingPanel>,
@@ -636,12 +654,15 @@ impl FloatingPanel {
     ) {
         let theme_spacing = theme_data.spacing;
         let colors = theme_data.colors();
+        let corner_radius = theme_data.shapes.extra_small;
 
         style_builder
             .absolute_position(panel.position)
             .border(UiRect::all(Val::Px(theme_spacing.borders.small)))
-            .border_color(colors.accent(Accent::Shadow))
-            .background_color(colors.surface(Surface::Surface));
+            .corner_radius(corner_radius)
+            .shape_color(colors.surface(Surface::Surface))
+            .shape_border_width(theme_spacing.borders.small)
+            .shape_border_color(colors.accent(Accent::Shadow));
 
         style_builder
             .animated()
@@ -666,7 +687,9 @@ impl FloatingPanel {
             .width(Val::Percent(100.))
             .align_items(AlignItems::Center)
             .justify_content(JustifyContent::Start)
-            .background_color(colors.container(Container::SurfaceMid));
+            .background_color(Color::NONE)
+            .corner_radius(CornerRadius::top(corner_radius))
+            .shape_color(colors.container(Container::SurfaceMid));
 
         style_builder
             .switch_target(FloatingPanel::TITLE)
@@ -687,7 +710,8 @@ impl FloatingPanel {
         style_builder
             .switch_target(FloatingPanel::CLOSE_BUTTON_CONTAINER)
             .right(Val::Px(0.))
-            .background_color(colors.container(Container::SurfaceMid));
+            .corner_radius(CornerRadius::new(0., corner_radius, 0., 0.))
+            .shape_color(colors.container(Container::SurfaceMid));
 
         style_builder
             .switch_target(FloatingPanel::CONTENT_VIEW)
@@ -825,6 +849,7 @@ impl FloatingPanel {
             FloatingPanelTitle { panel },
             TrackedInteraction::default(),
             Draggable::default(),
+            DraggableConfig::clickable(),
             RelativeCursorPosition::default(),
         )
     }
@@ -921,6 +946,7 @@ pub trait UiFloatingPanelExt {
 
 impl UiFloatingPanelExt for UiBuilder<'_, Entity> {
     /// A floating panel that can be optionally dragable, foldable, and closable.
+    /// Double-clicking the title folds a foldable panel.
     /// 
     /// ### PseudoState usage
     /// - `PseudoState::Folded` is used when the panel is folded
@@ -15,9 +15,12 @@ impl Plugin for ResizeHandlePlugin {
         app.add_plugins(ComponentThemePlugin::<ResizeHandles>::default())
             .add_systems(
                 Update,
-                update_cursor_on_resize_handles
-                    .run_if(should_update_resize_handle_cursor)
-                    .after(FluxInteractionUpdate),
+                (
+                    update_cursor_on_resize_handles
+                        .run_if(should_update_resize_handle_cursor)
+                        .after(FluxInteractionUpdate),
+                    update_resize_handles_on_drag.after(DraggableUpdate),
+                ),
             );
     }
 }
@@ -151,6 +154,33 @@ impl ResizeDirection {
     }
 }
 
+fn update_resize_handles_on_drag(
+    q_draggable: Query<(Entity, &Draggable, &Parent), Changed<Draggable>>,
+    q_handles: Query<(), With<ResizeHandle>>,
+    q_resize_handles: Query<(), With<ResizeHandles>>,
+    mut commands: Commands,
+) {
+    for (entity, draggable, parent) in &q_draggable {
+        if !q_handles.contains(entity) || !q_resize_handles.contains(parent.get()) {
+            continue;
+        }
+
+        match draggable.state {
+            DragState::DragStart => {
+                commands
+                    .entity(parent.get())
+                    .add_pseudo_state(PseudoState::Dragged);
+            }
+            DragState::DragEnd | DragState::DragCanceled => {
+                commands
+                    .entity(parent.get())
+                    .remove_pseudo_state(PseudoState::Dragged);
+            }
+            _ => (),
+        }
+    }
+}
+
 #[derive(Component, Debug, Default, Reflect)]
 #[reflect(Component)]
 pub struct ResizeHandle {
@@ -338,8 +368,12 @@ impl ResizeHandles {
             ResizeHandles::resizable_north_west,
         );
 
+        let dragged_theme =
+            PseudoTheme::deferred_world(vec![PseudoState::Dragged], ResizeHandles::dragged_style);
+
         Theme::new(vec![
             base_theme,
+            dragged_theme,
             theme_north,
             theme_north_north_west,
             theme_north_north_east,
@@ -494,6 +528,45 @@ impl ResizeHandles {
             .copy_from(interaction_animation);
     }
 
+    // Only the handle being dragged is pressed, so only its color fades
+    fn dragged_style(
+        style_builder: &mut StyleBuilder,
+        _entity: Entity,
+        _: &ResizeHandles,
+        world: &World,
+    ) {
+        let theme_data = world.resource::<ThemeData>();
+        let interaction_animation = theme_data.delayed_interaction_animation;
+        let colors = theme_data.colors();
+
+        let handle_color = AnimatedVals {
+            idle: Color::NONE,
+            hover: colors.accent(Accent::Outline).into(),
+            press: colors
+                .accent(Accent::Outline)
+                .with_a(theme_data.dragged_opacity)
+                .into(),
+            ..default()
+        };
+
+        for handle in [
+            ResizeHandles::HANDLE_NORTH,
+            ResizeHandles::HANDLE_NORTH_EAST,
+            ResizeHandles::HANDLE_EAST,
+            ResizeHandles::HANDLE_SOUTH_EAST,
+            ResizeHandles::HANDLE_SOUTH,
+            ResizeHandles::HANDLE_SOUTH_WEST,
+            ResizeHandles::HANDLE_WEST,
+            ResizeHandles::HANDLE_NORTH_WEST,
+        ] {
+            style_builder
+                .switch_placement(handle)
+                .animated()
+                .background_color(handle_color.clone())
+                .copy_from(interaction_animation);
+        }
+    }
+
     // North handle
     fn resizable_north(style_builder: &mut StyleBuilder, _theme_data: &ThemeData) {
         style_builder
@@ -694,6 +767,7 @@ impl UiResizeHandlesExt for UiBuilder<'_, Entity> {
     /// 
     /// ### PseudoState usage
     /// - `PseudoState::Resizable(_)` states are used to indicate which direction the container is resizable in.
+    /// - `PseudoState::Dragged` is used while one of the handles is dragged.
     fn resize_handles(
         &mut self,
         marker: impl Bundle + Clone,
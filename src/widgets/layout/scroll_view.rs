@@ -1,19 +1,33 @@
 use bevy::{
+    ecs::system::{EntityCommand, EntityCommands},
     input::mouse::MouseScrollUnit,
     prelude::*,
-    ui::{FocusPolicy, RelativeCursorPosition},
+    ui::{CalculatedClip, FocusPolicy, RelativeCursorPosition},
 };
 
+use sickle_math::ease::{Ease, ValueEasing};
 use sickle_ui_scaffold::prelude::*;
 
 use super::container::UiContainerExt;
 
+// Movement before dragging the content scrolls, so taps still reach its children
+const CONTENT_DRAG_THRESHOLD: f32 = 4.;
+// Share of the drag movement past the content edges that is applied as overscroll
+const OVERSCROLL_RESISTANCE: f32 = 0.5;
+// Rate at which the overscroll springs back, per second
+const OVERSCROLL_SPRING: f32 = 12.;
+// Fling speeds (pixels per second) below this stop the content
+const MIN_FLING_VELOCITY: f32 = 20.;
+// Releases later than this (seconds) after the last drag movement do not fling
+const FLING_RELEASE_WINDOW: f32 = 0.05;
+
 // TODO: implement click-to-scroll
 pub struct ScrollViewPlugin;
 
 impl Plugin for ScrollViewPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(ComponentThemePlugin::<ScrollView>::default())
+            .add_event::<ScrollViewChanged>()
             .add_systems(
                 Update,
                 (
@@ -21,6 +35,9 @@ impl Plugin for ScrollViewPlugin {
                     update_scroll_view_on_content_change,
                     update_scroll_view_on_scroll.after(ScrollableUpdate),
                     update_scroll_view_on_drag.after(DraggableUpdate),
+                    update_scroll_view_on_content_drag.after(DraggableUpdate),
+                    update_scroll_view_on_content_touch,
+                    update_scroll_view_motion,
                     update_scroll_view_offset,
                     update_scroll_view_layout.in_set(ScrollViewLayoutUpdate),
                 )
@@ -87,15 +104,23 @@ fn update_scroll_view_on_scroll(
             continue;
         }
 
-        let offset = match axis {
+        let diff = scroll_view.config.scroll_distance(diff, unit);
+        let diff = match axis {
             ScrollAxis::Horizontal => Vec2 { x: diff, y: 0. },
             ScrollAxis::Vertical => Vec2 { x: 0., y: diff },
         };
-        let diff = match unit {
-            MouseScrollUnit::Line => offset * 20.,
-            MouseScrollUnit::Pixel => offset,
-        };
-        scroll_view.scroll_offset = scroll_view.scroll_offset + diff;
+
+        // Pixel scrolls (i.e. touchpads) are already smooth
+        if unit == MouseScrollUnit::Pixel {
+            scroll_view.velocity = Vec2::ZERO;
+            scroll_view.animation = None;
+            scroll_view.requested_offset = None;
+            scroll_view.scroll_offset += diff;
+        } else {
+            scroll_view.scroll_by(diff);
+            // Input never waits for the content to grow
+            scroll_view.requested_offset = None;
+        }
     }
 }
 
@@ -105,8 +130,23 @@ fn update_scroll_view_on_drag(
     q_draggable: Query<(Entity, &Draggable, &ScrollBarHandle), Changed<Draggable>>,
     q_node: Query<&Node>,
     mut q_scroll_view: Query<&mut ScrollView>,
+    mut commands: Commands,
 ) {
     for (entity, draggable, bar_handle) in &q_draggable {
+        match draggable.state {
+            DragState::DragStart => {
+                commands
+                    .entity(bar_handle.scroll_view)
+                    .add_pseudo_state(PseudoState::Dragged);
+            }
+            DragState::DragEnd | DragState::DragCanceled => {
+                commands
+                    .entity(bar_handle.scroll_view)
+                    .remove_pseudo_state(PseudoState::Dragged);
+            }
+            _ => (),
+        }
+
         if draggable.state == DragState::Inactive
             || draggable.state == DragState::MaybeDragged
             || draggable.state == DragState::DragCanceled
@@ -121,6 +161,10 @@ fn update_scroll_view_on_drag(
             continue;
         }
 
+        scroll_view.animation = None;
+        scroll_view.requested_offset = None;
+        scroll_view.velocity = Vec2::ZERO;
+
         let Some(diff) = draggable.diff else {
             continue;
         };
@@ -165,16 +209,215 @@ fn update_scroll_view_on_drag(
     }
 }
 
+// Fling velocity follows the pointer, so it is measured in real time
+fn update_scroll_view_on_content_drag(
+    time: Res<Time<Real>>,
+    q_draggable: Query<(&Draggable, &ScrollViewViewport), Changed<Draggable>>,
+    mut q_scroll_view: Query<&mut ScrollView>,
+) {
+    let now = time.elapsed_seconds();
+
+    for (draggable, viewport) in &q_draggable {
+        let Ok(mut scroll_view) = q_scroll_view.get_mut(viewport.scroll_view) else {
+            continue;
+        };
+
+        match draggable.state {
+            DragState::DragStart | DragState::Dragging => {
+                if scroll_view.disabled {
+                    continue;
+                }
+
+                let Some(diff) = draggable.diff else {
+                    continue;
+                };
+
+                // Content follows the pointer, so scrolling goes the opposite way
+                scroll_view.drag_content(-diff, now, draggable.state == DragState::DragStart);
+            }
+            DragState::DragEnd | DragState::DragCanceled => {
+                scroll_view.release_content(now);
+            }
+            _ => (),
+        }
+    }
+}
+
+// Touches are hit-tested against the viewport rect instead of relying on its `Interaction`,
+// so content that blocks the viewport (i.e. buttons) can still be dragged
+fn update_scroll_view_on_content_touch(
+    time: Res<Time<Real>>,
+    r_touches: Res<Touches>,
+    ui_scale: Res<UiScale>,
+    q_viewports: Query<(
+        &ScrollViewViewport,
+        &Node,
+        &GlobalTransform,
+        &InheritedVisibility,
+        Option<&CalculatedClip>,
+    )>,
+    mut q_scroll_view: Query<&mut ScrollView>,
+) {
+    let now = time.elapsed_seconds();
+
+    for touch in r_touches.iter_just_pressed() {
+        let position = touch.position() / ui_scale.0;
+
+        // Nested scroll views are drawn above the ones they are in and take the touch
+        let touched = q_viewports
+            .iter()
+            .filter(|(_, node, transform, visibility, clip)| {
+                visibility.get()
+                    && node.logical_rect(transform).contains(position)
+                    && clip.is_none_or(|clip| clip.clip.contains(position))
+            })
+            .max_by_key(|(_, node, ..)| node.stack_index());
+        let Some((viewport, ..)) = touched else {
+            continue;
+        };
+        let Ok(mut scroll_view) = q_scroll_view.get_mut(viewport.scroll_view) else {
+            continue;
+        };
+        if scroll_view.disabled || scroll_view.content_touch.is_some() {
+            continue;
+        }
+
+        scroll_view.content_touch = ContentTouch {
+            id: touch.id(),
+            origin: position,
+            position,
+            dragging: false,
+        }
+        .into();
+    }
+
+    for mut scroll_view in &mut q_scroll_view {
+        let Some(mut content_touch) = scroll_view.content_touch else {
+            continue;
+        };
+
+        let Some(touch) = r_touches.get_pressed(content_touch.id) else {
+            scroll_view.content_touch = None;
+            if content_touch.dragging {
+                scroll_view.release_content(now);
+            }
+            continue;
+        };
+
+        let position = touch.position() / ui_scale.0;
+        if scroll_view.disabled || position == content_touch.position {
+            continue;
+        }
+
+        let start = !content_touch.dragging;
+        if start && position.distance(content_touch.origin) < CONTENT_DRAG_THRESHOLD {
+            continue;
+        }
+
+        // Content follows the touch, so scrolling goes the opposite way
+        let delta = content_touch.position - position;
+        content_touch.position = position;
+        content_touch.dragging = true;
+        scroll_view.content_touch = content_touch.into();
+        scroll_view.drag_content(delta, now, start);
+    }
+}
+
+// Scrolling answers input, so it keeps moving while the UI clock is paused or stepped
+fn update_scroll_view_motion(time: Res<Time<Real>>, mut q_scroll_views: Query<&mut ScrollView>) {
+    let delta_seconds = time.delta_seconds();
+    if delta_seconds == 0. {
+        return;
+    }
+
+    for mut scroll_view in &mut q_scroll_views {
+        // Only touch for change when in motion
+        let in_motion = scroll_view.animation.is_some()
+            || scroll_view.velocity != Vec2::ZERO
+            || (!scroll_view.is_dragged && scroll_view.overscroll != Vec2::ZERO);
+        if !in_motion {
+            continue;
+        }
+
+        if scroll_view.disabled {
+            scroll_view.animation = None;
+            scroll_view.velocity = Vec2::ZERO;
+            scroll_view.overscroll = Vec2::ZERO;
+            continue;
+        }
+
+        if let Some(mut animation) = scroll_view.animation {
+            animation.elapsed += delta_seconds;
+            // The duration may have been set to zero while animating
+            let duration = scroll_view.config.smooth_scroll_duration;
+            let progress = match duration > 0. {
+                true => animation.elapsed / duration,
+                false => 1.,
+            };
+
+            if progress < 1. {
+                let eased = progress.ease(scroll_view.config.smooth_scroll_ease);
+                scroll_view.scroll_offset = animation.from.lerp(animation.to, eased);
+                scroll_view.animation = animation.into();
+            } else {
+                scroll_view.scroll_offset = animation.to;
+                scroll_view.animation = None;
+                // The target was clamped to the laid out content, the request is done
+                if scroll_view.laid_out {
+                    scroll_view.requested_offset = None;
+                }
+            }
+        }
+
+        if scroll_view.velocity != Vec2::ZERO {
+            let velocity = scroll_view.velocity;
+            scroll_view.scroll_by_with_overscroll(velocity * delta_seconds);
+
+            let decay = (-scroll_view.config.friction * delta_seconds).exp();
+            let mut velocity = velocity * decay;
+
+            // Stop at the edges, the overscroll springs back
+            let overscroll = scroll_view.overscroll;
+            if overscroll.x != 0. {
+                velocity.x = 0.;
+            }
+            if overscroll.y != 0. {
+                velocity.y = 0.;
+            }
+            if velocity.length() < MIN_FLING_VELOCITY {
+                velocity = Vec2::ZERO;
+            }
+
+            scroll_view.velocity = velocity;
+        }
+
+        if !scroll_view.is_dragged && scroll_view.overscroll != Vec2::ZERO {
+            let overscroll = scroll_view.overscroll * (-OVERSCROLL_SPRING * delta_seconds).exp();
+            scroll_view.overscroll = match overscroll.length() < 0.5 {
+                true => Vec2::ZERO,
+                false => overscroll,
+            };
+        }
+    }
+}
+
 fn update_scroll_view_offset(
-    mut q_scroll_views: Query<(&Node, &mut ScrollView), Or<(Changed<ScrollView>, Changed<Node>)>>,
+    mut q_scroll_views: Query<
+        (Entity, &Node, &mut ScrollView),
+        Or<(Changed<ScrollView>, Changed<Node>)>,
+    >,
     q_node: Query<&Node>,
+    mut changed_events: EventWriter<ScrollViewChanged>,
 ) {
-    for (container_node, mut scroll_view) in &mut q_scroll_views {
+    for (entity, container_node, mut scroll_view) in &mut q_scroll_views {
         let container_width = container_node.unrounded_size().x;
         let container_height = container_node.unrounded_size().y;
         if container_width == 0. || container_height == 0. {
             continue;
         }
+        if !scroll_view.laid_out {
+            scroll_view.laid_out = true;
+        }
 
         // Unsafe unwrap: if a ScrollView's content doesn't have a Node we panic!
         let content_node = q_node.get(scroll_view.content_container).unwrap();
@@ -205,10 +448,41 @@ fn update_scroll_view_offset(
             y: overflow_y,
         };
 
-        scroll_view.visible_ratio = Vec2 {
+        // Offsets requested before the first layout are kept until the content is large enough
+        if let Some(requested) = scroll_view.requested_offset {
+            let target = requested.min(scroll_view.overflow.max(Vec2::ZERO));
+            match scroll_view.animation {
+                Some(mut animation) => {
+                    animation.to = target;
+                    scroll_view.animation = animation.into();
+                }
+                None => scroll_view.scroll_offset = target,
+            }
+            if target == requested {
+                scroll_view.requested_offset = None;
+            }
+        }
+
+        let visible_ratio = Vec2 {
             x: (container_width / content_width).clamp(0., 1.),
             y: (container_height / content_height).clamp(0., 1.),
         };
+        scroll_view.visible_ratio = visible_ratio;
+
+        let reported = (
+            scroll_view.scroll_offset,
+            scroll_view.overflow,
+            visible_ratio,
+        );
+        if scroll_view.reported != reported {
+            scroll_view.reported = reported;
+            changed_events.send(ScrollViewChanged {
+                scroll_view: entity,
+                offset: scroll_view.scroll_offset,
+                overflow: scroll_view.overflow,
+                visible_ratio,
+            });
+        }
     }
 }
 
@@ -235,13 +509,15 @@ fn update_scroll_view_layout(
         // Update content scroll
         let scroll_offset_x = scroll_view.scroll_offset.x;
         let scroll_offset_y = scroll_view.scroll_offset.y;
+        let overscroll_x = scroll_view.overscroll.x;
+        let overscroll_y = scroll_view.overscroll.y;
         let overflow_x = scroll_view.overflow.x;
         let overflow_y = scroll_view.overflow.y;
 
         if overflow_x > 0. {
             commands
                 .style_unchecked(scroll_view.content_container)
-                .left(Val::Px(-scroll_offset_x));
+                .left(Val::Px(-(scroll_offset_x + overscroll_x)));
             commands
                 .entity(entity)
                 .add_pseudo_state(PseudoState::OverflowX);
@@ -270,7 +546,7 @@ fn update_scroll_view_layout(
         if overflow_y > 0. {
             commands
                 .style_unchecked(scroll_view.content_container)
-                .top(Val::Px(-scroll_offset_y));
+                .top(Val::Px(-(scroll_offset_y + overscroll_y)));
             commands
                 .entity(entity)
                 .add_pseudo_state(PseudoState::OverflowY);
@@ -360,6 +636,101 @@ impl Default for ScrollViewViewport {
     }
 }
 
+#[derive(Clone, Copy, Debug, Reflect)]
+pub struct ScrollViewConfig {
+    /// Pixels scrolled per line by `MouseScrollUnit::Line` wheel events
+    pub line_height: f32,
+    /// Seconds it takes to animate to the target offset of a line scroll. Zero scrolls instantly.
+    pub smooth_scroll_duration: f32,
+    pub smooth_scroll_ease: Ease,
+    /// Whether content keeps moving after it is dragged and released
+    pub fling: bool,
+    /// Rate at which the fling slows down, per second
+    pub friction: f32,
+    /// How far (in pixels) the content can be dragged past its edges. Zero disables overscroll.
+    pub max_overscroll: f32,
+}
+
+impl Default for ScrollViewConfig {
+    fn default() -> Self {
+        Self {
+            line_height: 20.,
+            smooth_scroll_duration: 0.15,
+            smooth_scroll_ease: Ease::OutCubic,
+            fling: true,
+            friction: 4.,
+            max_overscroll: 60.,
+        }
+    }
+}
+
+impl ScrollViewConfig {
+    /// Converts a scroll amount in the given unit to pixels
+    pub fn scroll_distance(&self, diff: f32, unit: MouseScrollUnit) -> f32 {
+        match unit {
+            MouseScrollUnit::Line => diff * self.line_height,
+            MouseScrollUnit::Pixel => diff,
+        }
+    }
+}
+
+/// Sent when the scroll offset, overflow or visible ratio of a `ScrollView` changes
+#[derive(Event, Clone, Copy, Debug)]
+pub struct ScrollViewChanged {
+    pub scroll_view: Entity,
+    pub offset: Vec2,
+    pub overflow: Vec2,
+    pub visible_ratio: Vec2,
+}
+
+/// Where the target of `scroll_into_view` ends up in the viewport
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect]
+pub enum ScrollAlignment {
+    Start,
+    Center,
+    End,
+    /// Scroll as little as possible to make the target fully visible
+    #[default]
+    Nearest,
+}
+
+impl ScrollAlignment {
+    /// Offset along one axis that shows the range `start..end` of the content in a viewport of `size`
+    pub fn offset(&self, current: f32, start: f32, end: f32, size: f32) -> f32 {
+        match self {
+            ScrollAlignment::Start => start,
+            ScrollAlignment::Center => (start + end - size) / 2.,
+            ScrollAlignment::End => end - size,
+            ScrollAlignment::Nearest => {
+                if start < current || end - start > size {
+                    start
+                } else if end > current + size {
+                    end - size
+                } else {
+                    current
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Reflect)]
+struct ScrollAnimation {
+    from: Vec2,
+    to: Vec2,
+    elapsed: f32,
+}
+
+/// A touch that started in the viewport and may drag the content
+#[derive(Clone, Copy, Debug, Reflect)]
+struct ContentTouch {
+    id: u64,
+    origin: Vec2,
+    position: Vec2,
+    dragging: bool,
+}
+
 #[derive(Component, Clone, Debug, Reflect)]
 #[reflect(Component)]
 pub struct ScrollView {
@@ -370,10 +741,21 @@ pub struct ScrollView {
     vertical_scroll_bar: Entity,
     vertical_scroll_bar_handle: Entity,
     scroll_offset: Vec2,
+    requested_offset: Option<Vec2>,
+    laid_out: bool,
     overflow: Vec2,
     visible_ratio: Vec2,
     restricted_to: Option<ScrollAxis>,
+    animation: Option<ScrollAnimation>,
+    velocity: Vec2,
+    drag_velocity: Vec2,
+    last_drag_time: f32,
+    is_dragged: bool,
+    content_touch: Option<ContentTouch>,
+    overscroll: Vec2,
+    reported: (Vec2, Vec2, Vec2),
     pub disabled: bool,
+    pub config: ScrollViewConfig,
 }
 
 impl Default for ScrollView {
@@ -386,10 +768,21 @@ impl Default for ScrollView {
             vertical_scroll_bar: Entity::PLACEHOLDER,
             vertical_scroll_bar_handle: Entity::PLACEHOLDER,
             scroll_offset: Vec2::ZERO,
+            requested_offset: None,
+            laid_out: false,
             overflow: Vec2::ZERO,
             visible_ratio: Vec2::ZERO,
             disabled: false,
             restricted_to: None,
+            animation: None,
+            velocity: Vec2::ZERO,
+            drag_velocity: Vec2::ZERO,
+            last_drag_time: 0.,
+            is_dragged: false,
+            content_touch: None,
+            overscroll: Vec2::ZERO,
+            reported: (Vec2::ZERO, Vec2::ZERO, Vec2::ZERO),
+            config: Default::default(),
         }
     }
 }
@@ -441,6 +834,140 @@ impl ScrollView {
         self.viewport
     }
 
+    pub fn content_container_id(&self) -> Entity {
+        self.content_container
+    }
+
+    pub fn scroll_offset(&self) -> Vec2 {
+        self.scroll_offset
+    }
+
+    pub fn overflow(&self) -> Vec2 {
+        self.overflow
+    }
+
+    pub fn visible_ratio(&self) -> Vec2 {
+        self.visible_ratio
+    }
+
+    /// Scrolls to the offset. Animated unless `ScrollViewConfig::smooth_scroll_duration` is zero.
+    ///
+    /// The offset is clamped to the overflow of the last layout. Offsets set right after spawning,
+    /// before the view is first laid out, wait for the content instead, i.e. to restore a saved
+    /// offset. They are dropped once reached, when the scroll animation ends or on input.
+    pub fn scroll_to(&mut self, offset: Vec2) {
+        let mut target = offset.max(Vec2::ZERO);
+        if self.laid_out {
+            target = target.min(self.overflow.max(Vec2::ZERO));
+            self.requested_offset = None;
+        } else {
+            self.requested_offset = target.into();
+        }
+
+        self.velocity = Vec2::ZERO;
+        if self.config.smooth_scroll_duration <= 0. {
+            self.animation = None;
+            self.scroll_offset = target;
+        } else {
+            self.animation = ScrollAnimation {
+                from: self.scroll_offset,
+                to: target,
+                elapsed: 0.,
+            }
+            .into();
+        }
+    }
+
+    /// Scrolls relative to the offset the view is currently scrolling to
+    pub fn scroll_by(&mut self, delta: Vec2) {
+        // Consecutive calls extend the running animation
+        let current = match self.animation {
+            Some(animation) => animation.to,
+            None => self.scroll_offset,
+        };
+
+        self.scroll_to(current + delta);
+    }
+
+    /// Moves the content by a drag step. `start` stops the motion the view had before the drag.
+    fn drag_content(&mut self, delta: Vec2, now: f32, start: bool) {
+        if start {
+            self.animation = None;
+            self.velocity = Vec2::ZERO;
+            self.drag_velocity = Vec2::ZERO;
+        } else {
+            let elapsed = (now - self.last_drag_time).max(f32::EPSILON);
+            self.drag_velocity = delta / elapsed;
+        }
+
+        self.is_dragged = true;
+        self.last_drag_time = now;
+        self.scroll_by_with_overscroll(delta);
+    }
+
+    /// Ends a content drag, flinging the content if it was released while moving
+    fn release_content(&mut self, now: f32) {
+        if !self.is_dragged {
+            return;
+        }
+
+        let released_while_moving = now - self.last_drag_time < FLING_RELEASE_WINDOW;
+        self.is_dragged = false;
+        self.velocity = match self.config.fling && released_while_moving {
+            true => self.drag_velocity,
+            false => Vec2::ZERO,
+        };
+        self.drag_velocity = Vec2::ZERO;
+    }
+
+    /// Scrolls within the overflow, moving the rest into the overscroll
+    fn scroll_by_with_overscroll(&mut self, delta: Vec2) {
+        self.requested_offset = None;
+
+        // Moving back from an overscrolled edge closes the gap before scrolling the content
+        let mut delta = delta;
+        for axis in 0..2 {
+            let overscroll = self.overscroll[axis];
+            if overscroll == 0. || delta[axis] == 0. || overscroll.signum() == delta[axis].signum()
+            {
+                continue;
+            }
+
+            let remaining = overscroll + delta[axis] * OVERSCROLL_RESISTANCE;
+            if remaining.signum() == overscroll.signum() {
+                self.overscroll[axis] = remaining;
+                delta[axis] = 0.;
+            } else {
+                self.overscroll[axis] = 0.;
+                delta[axis] = remaining / OVERSCROLL_RESISTANCE;
+            }
+        }
+
+        let overflow = self.overflow.max(Vec2::ZERO);
+        let max_overscroll = self.config.max_overscroll;
+        let unclamped = self.scroll_offset + delta;
+        let clamped = unclamped.clamp(Vec2::ZERO, overflow);
+
+        self.scroll_offset = clamped;
+        if max_overscroll > 0. {
+            let overscroll = self.overscroll + (unclamped - clamped) * OVERSCROLL_RESISTANCE;
+            // Only overflowing axes can overscroll
+            self.overscroll = Vec2::new(
+                if self.overflow.x > 0. {
+                    overscroll.x
+                } else {
+                    0.
+                },
+                if self.overflow.y > 0. {
+                    overscroll.y
+                } else {
+                    0.
+                },
+            )
+            .clamp(Vec2::splat(-max_overscroll), Vec2::splat(max_overscroll));
+        }
+    }
+
     pub fn theme() -> Theme<ScrollView> {
         let base_theme = PseudoTheme::deferred_context(None, ScrollView::primary_style);
         let disabled_theme =
@@ -453,6 +980,8 @@ impl ScrollView {
             vec![PseudoState::OverflowX, PseudoState::OverflowY],
             ScrollView::overflow_xy_style,
         );
+        let dragged_theme =
+            PseudoTheme::deferred(vec![PseudoState::Dragged], ScrollView::dragged_style);
 
         Theme::new(vec![
             base_theme,
@@ -460,6 +989,7 @@ impl ScrollView {
             overflow_x_theme,
             overflow_y_theme,
             overflow_xy_theme,
+            dragged_theme,
         ])
     }
 
@@ -580,6 +1110,32 @@ impl ScrollView {
             ));
     }
 
+    // Only the handle being dragged is pressed, so only its color fades
+    fn dragged_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let colors = theme_data.colors();
+        let handle_color = AnimatedVals {
+            idle: colors.accent(Accent::Tertiary).with_a(0.5),
+            hover: colors.accent(Accent::Tertiary).with_a(0.8).into(),
+            press: colors
+                .accent(Accent::Tertiary)
+                .with_a(theme_data.dragged_opacity)
+                .into(),
+            ..default()
+        };
+
+        style_builder
+            .switch_context(ScrollView::HORIZONTAL_SCROLL_HANDLE, None)
+            .animated()
+            .background_color(handle_color.clone())
+            .copy_from(theme_data.interaction_animation);
+
+        style_builder
+            .switch_context(ScrollView::VERTICAL_SCROLL_HANDLE, None)
+            .animated()
+            .background_color(handle_color)
+            .copy_from(theme_data.interaction_animation);
+    }
+
     fn frame() -> impl Bundle {
         (
             Name::new("Scroll View"),
@@ -609,7 +1165,17 @@ impl ScrollView {
                 ..default()
             },
             Interaction::default(),
+            TrackedInteraction::default(),
             Scrollable::default(),
+            Draggable::default(),
+            // Touches are tracked by the scroll view itself
+            DraggableConfig {
+                threshold: CONTENT_DRAG_THRESHOLD,
+                buttons: vec![MouseButton::Middle],
+                touch: false,
+                ..default()
+            },
+            RelativeCursorPosition::default(),
             ScrollViewViewport { scroll_view },
             LockedStyleAttributes::from_vec(vec![
                 LockableStyleAttribute::PositionType,
@@ -720,6 +1286,10 @@ impl ScrollView {
             },
             TrackedInteraction::default(),
             Draggable::default(),
+            DraggableConfig::with_axis(match axis {
+                ScrollAxis::Horizontal => DragAxis::Horizontal,
+                ScrollAxis::Vertical => DragAxis::Vertical,
+            }),
             RelativeCursorPosition::default(),
             Scrollable::default(),
             ScrollBarHandle { axis, scroll_view },
@@ -744,11 +1314,12 @@ pub trait UiScrollViewExt {
 impl UiScrollViewExt for UiBuilder<'_, Entity> {
     /// A simple scroll view. When the content overflows, scroll bars appear for the given direction.
     /// Can be restricted to scroll only on one axis.
-    /// 
+    ///
     /// ### PseudoState usage
     /// - `PseudoState::Disabled` is used when the view is disabled, preventing scroll bars from showing
     /// - `PseudoState::OverflowX` and `PseudoState::OverflowY` is used to indicate which axis the content
     /// overflows on.
+    /// - `PseudoState::Dragged` is used while a scroll bar handle is dragged
     fn scroll_view(
         &mut self,
         restrict_to: impl Into<Option<ScrollAxis>>,
@@ -815,3 +1386,133 @@ impl UiScrollViewExt for UiBuilder<'_, Entity> {
         frame
     }
 }
+
+struct ScrollTo {
+    offset: Vec2,
+    relative: bool,
+}
+
+impl EntityCommand for ScrollTo {
+    fn apply(self, entity: Entity, world: &mut World) {
+        let Some(mut scroll_view) = world.get_mut::<ScrollView>(entity) else {
+            warn!(
+                "Failed to scroll entity {:?}: No ScrollView component found!",
+                entity
+            );
+
+            return;
+        };
+
+        match self.relative {
+            true => scroll_view.scroll_by(self.offset),
+            false => scroll_view.scroll_to(self.offset),
+        }
+    }
+}
+
+struct ScrollIntoView {
+    target: Entity,
+    alignment: ScrollAlignment,
+}
+
+impl EntityCommand for ScrollIntoView {
+    fn apply(self, entity: Entity, world: &mut World) {
+        let Some(scroll_view) = world.get::<ScrollView>(entity) else {
+            warn!(
+                "Failed to scroll {:?} into view of {:?}: No ScrollView component found!",
+                self.target, entity
+            );
+
+            return;
+        };
+        let content = scroll_view.content_container;
+
+        let mut ancestor = world.get::<Parent>(self.target).map(Parent::get);
+        while let Some(current) = ancestor {
+            if current == content {
+                break;
+            }
+            ancestor = world.get::<Parent>(current).map(Parent::get);
+        }
+        if ancestor != Some(content) {
+            warn!(
+                "Failed to scroll {:?} into view of {:?}: Not in the content of the ScrollView",
+                self.target, entity
+            );
+
+            return;
+        }
+
+        let (Some(view_node), Some(content_node), Some(content_transform)) = (
+            world.get::<Node>(entity),
+            world.get::<Node>(content),
+            world.get::<GlobalTransform>(content),
+        ) else {
+            return;
+        };
+        let (Some(target_node), Some(target_transform)) = (
+            world.get::<Node>(self.target),
+            world.get::<GlobalTransform>(self.target),
+        ) else {
+            return;
+        };
+
+        // Position inside the content, independent of the current scroll
+        let content_rect = content_node.logical_rect(content_transform);
+        let target_rect = target_node.logical_rect(target_transform);
+        let start = target_rect.min - content_rect.min;
+        let end = target_rect.max - content_rect.min;
+        let view_size = view_node.unrounded_size();
+
+        let mut scroll_view = world.get_mut::<ScrollView>(entity).unwrap();
+        let current = match scroll_view.animation {
+            Some(animation) => animation.to,
+            None => scroll_view.scroll_offset,
+        };
+        let alignment = self.alignment;
+        let offset = Vec2::new(
+            alignment.offset(current.x, start.x, end.x, view_size.x),
+            alignment.offset(current.y, start.y, end.y, view_size.y),
+        );
+
+        if offset != current {
+            scroll_view.scroll_to(offset);
+        }
+    }
+}
+
+pub trait ManageScrollViewExt {
+    fn scroll_to(&mut self, offset: Vec2) -> &mut Self;
+    fn scroll_by(&mut self, delta: Vec2) -> &mut Self;
+    fn scroll_into_view(&mut self, target: Entity, alignment: ScrollAlignment) -> &mut Self;
+}
+
+impl ManageScrollViewExt for EntityCommands<'_> {
+    /// Scrolls the `ScrollView` to the offset, see `ScrollView::scroll_to`
+    fn scroll_to(&mut self, offset: Vec2) -> &mut Self {
+        self.add(ScrollTo {
+            offset,
+            relative: false,
+        });
+
+        self
+    }
+
+    /// Scrolls the `ScrollView` by the delta, see `ScrollView::scroll_by`
+    fn scroll_by(&mut self, delta: Vec2) -> &mut Self {
+        self.add(ScrollTo {
+            offset: delta,
+            relative: true,
+        });
+
+        self
+    }
+
+    /// Scrolls the `ScrollView` so that `target`, an entity in its content, is visible.
+    /// Uses the layout of the last frame.
+    fn scroll_into_view(&mut self, target: Entity, alignment: ScrollAlignment) -> &mut Self {
+        self.add(ScrollIntoView { target, alignment });
+
+        self
+    }
+}
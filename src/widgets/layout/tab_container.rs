@@ -1,4 +1,4 @@
-use bevy::{ecs::system::Command, prelude::*, ui::RelativeCursorPosition};
+use bevy::{ecs::system::Command, prelude::*, text::BreakLineOn, ui::RelativeCursorPosition};
 
 use sickle_macros::UiContext;
 use sickle_ui_scaffold::prelude::*;
@@ -60,6 +60,7 @@ impl Plugin for TabContainerPlugin {
             Update,
             (
                 update_tab_container_on_tab_press,
+                update_tab_container_on_navigation.after(NavigationUpdate),
                 update_tab_container_on_change,
                 update_sized_zone_resize_handles_on_tab_drag,
                 handle_tab_dragging,
@@ -307,6 +308,58 @@ fn update_tab_container_on_tab_press(
     }
 }
 
+fn update_tab_container_on_navigation(
+    mut r_focus: ResMut<FocusManager>,
+    mut events: EventReader<NavigationEvent>,
+    q_tab: Query<&Tab>,
+    q_children: Query<&Children>,
+    mut q_tab_container: Query<&mut TabContainer>,
+) {
+    for event in events.read() {
+        let Some(target) = event.target else {
+            continue;
+        };
+
+        match event.action {
+            NavigationAction::ConfirmPressed => {
+                let Ok(tab) = q_tab.get(target) else {
+                    continue;
+                };
+                let Ok(mut tab_container) = q_tab_container.get_mut(tab.container) else {
+                    continue;
+                };
+                let Ok(tabs) = q_children.get(tab_container.bar) else {
+                    continue;
+                };
+
+                if let Some(index) = tabs.iter().position(|id| *id == target) {
+                    tab_container.active = index;
+                }
+            }
+            NavigationAction::Back => {
+                // Step out of the active panel to its tab
+                let Ok(tab_container) = q_tab_container.get(target) else {
+                    continue;
+                };
+                let Ok(tabs) = q_children.get(tab_container.bar) else {
+                    continue;
+                };
+                let Some(active_tab) = tabs.get(tab_container.active) else {
+                    continue;
+                };
+
+                let on_tab = r_focus
+                    .focused()
+                    .is_some_and(|focused| tabs.contains(&focused));
+                if !on_tab && q_tab.contains(*active_tab) {
+                    r_focus.focus(*active_tab);
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
 fn update_tab_container_on_change(
     q_tab_containers: Query<&TabContainer, Changed<TabContainer>>,
     q_tab: Query<Entity, With<Tab>>,
@@ -414,8 +467,11 @@ fn handle_tab_dragging(
                     continue;
                 };
 
-                let left =
-                    transform.translation.truncate().x - (node.size().x / 2.) + bar_half_width;
+                // The tab catches up with the pointer movement below the drag threshold
+                let diff = draggable.diff.unwrap_or_default();
+                let left = transform.translation.truncate().x - (node.size().x / 2.)
+                    + bar_half_width
+                    + diff.x;
                 let placeholder = commands
                     .ui_builder(container.bar)
                     .tab_placeholder(node.size().x)
@@ -690,7 +746,9 @@ impl Tab {
         let base_theme = PseudoTheme::deferred(None, Tab::primary_style);
         let selected_theme =
             PseudoTheme::deferred(vec![PseudoState::Selected], Tab::selected_style);
-        Theme::new(vec![base_theme, selected_theme])
+        let focused_theme =
+            PseudoTheme::deferred(vec![PseudoState::Focused], ThemeData::inset_focus_ring);
+        Theme::new(vec![base_theme, selected_theme, focused_theme])
     }
 
     fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
@@ -700,6 +758,8 @@ impl Tab {
             .text
             .get(FontStyle::Body, FontScale::Medium, FontType::Regular);
 
+        ThemeData::no_focus_ring(style_builder);
+
         style_builder
             .padding(UiRect::axes(
                 Val::Px(theme_spacing.gaps.medium),
@@ -707,6 +767,8 @@ impl Tab {
             ))
             .border(UiRect::right(Val::Px(theme_spacing.gaps.extra_small)))
             .border_color(colors.accent(Accent::OutlineVariant))
+            .inherited_sized_font(font)
+            .inherited_font_color(colors.on(On::Surface))
             .animated()
             .background_color(AnimatedVals {
                 idle: colors.container(Container::SurfaceMid),
@@ -717,8 +779,7 @@ impl Tab {
 
         style_builder
             .switch_target(Tab::LABEL)
-            .sized_font(font)
-            .font_color(colors.on(On::Surface));
+            .line_break(BreakLineOn::NoWrap);
 
         style_builder
             .switch_target(Tab::PANEL)
@@ -742,7 +803,9 @@ impl Tab {
             NodeBundle::default(),
             Interaction::default(),
             TrackedInteraction::default(),
+            Focusable::default(),
             Draggable::default(),
+            DraggableConfig::clickable(),
             RelativeCursorPosition::default(),
             GenerateContextMenu::default(),
             LockedStyleAttributes::from_vec(vec![
@@ -969,6 +1032,7 @@ impl TabContainer {
             Name::new("Tab Container"),
             NodeBundle::default(),
             Interaction::default(),
+            BackNavigationHandler,
         )
     }
 
@@ -983,6 +1047,7 @@ impl TabContainer {
                 ..default()
             },
             Interaction::default(),
+            AnimateLayout::default(),
             LockedStyleAttributes::lock(LockableStyleAttribute::Overflow),
         )
     }
@@ -1053,8 +1118,12 @@ impl UiTabContainerSubExt for UiBuilder<'_, (Entity, TabContainer)> {
 
     /// Adds a tab to the TabContainer
     /// 
+    /// Tabs are focusable, the confirm action selects the focused tab. The back action from
+    /// within the panel moves focus to its tab.
+    ///
     /// ### PseudoState usage
     /// - `PseudoState::Selected` is added to the tab currently selected per TabContainer
+    /// - `PseudoState::Focused` is added to the tab with keyboard focus
     fn add_tab(
         &mut self,
         title: String,
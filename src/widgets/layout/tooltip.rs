@@ -0,0 +1,434 @@
+use std::{fmt, sync::Arc, time::Duration};
+
+use bevy::{input::mouse::MouseWheel, prelude::*, ui::FocusPolicy, window::PrimaryWindow};
+
+use sickle_ui_scaffold::{
+    flux_interaction::{FluxInteractionConfig, StopwatchLock},
+    prelude::*,
+    ui_commands::ManageFluxInteractionStopwatchLockExt,
+};
+
+use super::{
+    container::UiContainerExt,
+    label::{LabelConfig, UiLabelExt},
+};
+
+const TOOLTIP_Z_INDEX: i32 = 100010;
+
+pub struct TooltipPlugin;
+
+impl Plugin for TooltipPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(Update, TooltipUpdate.after(FluxInteractionUpdate))
+            .add_plugins(ComponentThemePlugin::<TooltipPanel>::default())
+            .add_systems(
+                Update,
+                (
+                    hide_tooltip_on_interaction,
+                    show_tooltip_on_hover,
+                    position_tooltip_panel,
+                )
+                    .chain()
+                    .in_set(TooltipUpdate),
+            )
+            .add_systems(PostUpdate, delete_orphaned_tooltip_panels);
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct TooltipUpdate;
+
+fn hide_tooltip_on_interaction(
+    mut r_wheel: EventReader<MouseWheel>,
+    mut q_tooltips: Query<(Ref<FluxInteraction>, &mut Tooltip)>,
+    mut commands: Commands,
+) {
+    let scrolled = r_wheel.read().count() > 0;
+
+    for (interaction, mut tooltip) in &mut q_tooltips {
+        if interaction.is_changed() {
+            match *interaction {
+                FluxInteraction::PointerLeave
+                | FluxInteraction::PressCanceled
+                | FluxInteraction::Disabled => tooltip.suppressed = false,
+                FluxInteraction::Pressed => tooltip.suppressed = true,
+                _ => (),
+            }
+        }
+
+        // Content under the pointer may have moved, wait for the next hover
+        if scrolled && *interaction == FluxInteraction::PointerEnter {
+            tooltip.suppressed = true;
+        }
+
+        if tooltip.suppressed || *interaction != FluxInteraction::PointerEnter {
+            if let Some(panel) = tooltip.panel {
+                commands.entity(panel).despawn_recursive();
+                tooltip.panel = None;
+            }
+        }
+    }
+}
+
+fn show_tooltip_on_hover(
+    r_config: Res<FluxInteractionConfig>,
+    r_theme_data: Res<ThemeData>,
+    mut q_tooltips: Query<(
+        Entity,
+        Ref<FluxInteraction>,
+        Option<&FluxInteractionStopwatch>,
+        &mut Tooltip,
+    )>,
+    q_parents: Query<&Parent>,
+    q_context_roots: Query<(), With<UiContextRoot>>,
+    mut commands: Commands,
+) {
+    let delay = r_theme_data.tooltip_delay;
+
+    for (entity, interaction, stopwatch, mut tooltip) in &mut q_tooltips {
+        if *interaction != FluxInteraction::PointerEnter {
+            continue;
+        }
+
+        if interaction.is_changed() && delay > r_config.max_interaction_duration {
+            // Keep the stopwatch around until the delay elapses
+            commands.entity(entity).lock_stopwatch(
+                Tooltip::STOPWATCH_LOCK,
+                StopwatchLock::Duration(Duration::from_secs_f32(delay)),
+            );
+        }
+
+        if tooltip.suppressed || tooltip.panel.is_some() {
+            continue;
+        }
+
+        let Some(stopwatch) = stopwatch else {
+            continue;
+        };
+
+        if stopwatch.0.elapsed_secs() < delay {
+            continue;
+        }
+
+        let mut root_node = entity;
+        for ancestor in q_parents.iter_ancestors(entity) {
+            root_node = ancestor;
+            if q_context_roots.contains(ancestor) {
+                break;
+            }
+        }
+
+        let content = tooltip.content.clone();
+        let mut label = None;
+        let panel = commands
+            .ui_builder(root_node)
+            .container(TooltipPanel::frame(), |container| match content {
+                TooltipContent::Text(text) => {
+                    label = container
+                        .label(LabelConfig {
+                            label: text,
+                            wrap: FlexWrap::Wrap,
+                            ..default()
+                        })
+                        .id()
+                        .into();
+                }
+                TooltipContent::Builder(spawn_children) => spawn_children(container),
+            })
+            .id();
+
+        commands.entity(panel).insert(TooltipPanel {
+            target: entity,
+            label,
+        });
+
+        tooltip.panel = panel.into();
+    }
+}
+
+fn position_tooltip_panel(
+    r_theme_data: Res<ThemeData>,
+    r_ui_scale: Res<UiScale>,
+    mut q_panels: Query<(&TooltipPanel, &Node, &mut Style, &mut Visibility), Changed<Node>>,
+    q_targets: Query<(&Tooltip, &Node, &GlobalTransform)>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+) {
+    let Ok(window) = q_window.get_single() else {
+        return;
+    };
+
+    let container_size = Vec2::new(window.width(), window.height()) / r_ui_scale.0;
+    let gap = r_theme_data.spacing.gaps.small;
+
+    for (panel, node, mut style, mut visibility) in &mut q_panels {
+        let Ok((tooltip, target_node, target_transform)) = q_targets.get(panel.target) else {
+            continue;
+        };
+
+        let position = Tooltip::panel_position(
+            tooltip.placement,
+            target_node.logical_rect(target_transform),
+            node.size(),
+            container_size,
+            gap,
+        );
+
+        style.left = Val::Px(position.x);
+        style.top = Val::Px(position.y);
+        *visibility = Visibility::Inherited;
+    }
+}
+
+fn delete_orphaned_tooltip_panels(
+    q_panels: Query<(Entity, &TooltipPanel)>,
+    mut commands: Commands,
+) {
+    for (entity, panel) in &q_panels {
+        if commands.get_entity(panel.target).is_none() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect]
+pub enum TooltipPlacement {
+    Top,
+    #[default]
+    Bottom,
+    Left,
+    Right,
+}
+
+/// Spawns the content of a tooltip into its container
+pub type TooltipContentBuilder = Arc<dyn Fn(&mut UiBuilder<Entity>) + Send + Sync>;
+
+#[derive(Clone)]
+pub enum TooltipContent {
+    Text(String),
+    Builder(TooltipContentBuilder),
+}
+
+impl Default for TooltipContent {
+    fn default() -> Self {
+        Self::Text(String::new())
+    }
+}
+
+impl fmt::Debug for TooltipContent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Text(text) => f.debug_tuple("Text").field(text).finish(),
+            Self::Builder(_) => f.write_str("Builder"),
+        }
+    }
+}
+
+/// Shows explanatory content next to the entity after the pointer rests on it for
+/// `ThemeData::tooltip_delay` seconds.
+///
+/// Requires `TrackedInteraction`. The tooltip hides when the pointer leaves, presses
+/// or scrolls, and only shows again on the next hover.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct Tooltip {
+    #[reflect(ignore)]
+    content: TooltipContent,
+    placement: TooltipPlacement,
+    panel: Option<Entity>,
+    suppressed: bool,
+}
+
+impl Tooltip {
+    pub const STOPWATCH_LOCK: &'static str = "Tooltip";
+
+    pub fn text(text: impl Into<String>) -> Self {
+        Self {
+            content: TooltipContent::Text(text.into()),
+            ..default()
+        }
+    }
+
+    pub fn builder(
+        spawn_children: impl Fn(&mut UiBuilder<Entity>) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            content: TooltipContent::Builder(Arc::new(spawn_children)),
+            ..default()
+        }
+    }
+
+    /// The preferred side of the target. The tooltip flips to the opposite side when
+    /// it wouldn't fit in the window.
+    pub fn with_placement(self, placement: TooltipPlacement) -> Self {
+        Self { placement, ..self }
+    }
+
+    pub fn placement(&self) -> TooltipPlacement {
+        self.placement
+    }
+
+    pub fn is_shown(&self) -> bool {
+        self.panel.is_some()
+    }
+
+    /// Top left position of a tooltip of `size` placed around `target`, kept inside the container
+    pub fn panel_position(
+        placement: TooltipPlacement,
+        target: Rect,
+        size: Vec2,
+        container_size: Vec2,
+        gap: f32,
+    ) -> Vec2 {
+        let above = target.min.y - gap - size.y;
+        let below = target.max.y + gap;
+        let before = target.min.x - gap - size.x;
+        let after = target.max.x + gap;
+        let fits_above = above >= 0.;
+        let fits_below = below + size.y <= container_size.y;
+        let fits_before = before >= 0.;
+        let fits_after = after + size.x <= container_size.x;
+
+        let position = match placement {
+            TooltipPlacement::Top => Vec2::new(
+                target.center().x - size.x / 2.,
+                match fits_above || !fits_below {
+                    true => above,
+                    false => below,
+                },
+            ),
+            TooltipPlacement::Bottom => Vec2::new(
+                target.center().x - size.x / 2.,
+                match fits_below || !fits_above {
+                    true => below,
+                    false => above,
+                },
+            ),
+            TooltipPlacement::Left => Vec2::new(
+                match fits_before || !fits_after {
+                    true => before,
+                    false => after,
+                },
+                target.center().y - size.y / 2.,
+            ),
+            TooltipPlacement::Right => Vec2::new(
+                match fits_after || !fits_before {
+                    true => after,
+                    false => before,
+                },
+                target.center().y - size.y / 2.,
+            ),
+        };
+
+        position.min(container_size - size).max(Vec2::ZERO)
+    }
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct TooltipPanel {
+    target: Entity,
+    label: Option<Entity>,
+}
+
+impl Default for TooltipPanel {
+    fn default() -> Self {
+        Self {
+            target: Entity::PLACEHOLDER,
+            label: None,
+        }
+    }
+}
+
+impl UiContext for TooltipPanel {
+    fn get(&self, target: &str) -> Result<Entity, String> {
+        match target {
+            TooltipPanel::LABEL => self
+                .label
+                .ok_or("TooltipPanel has no label, its content is built by a callback".into()),
+            _ => Err(format!(
+                "{} doesn't exists for TooltipPanel. Possible contexts: {:?}",
+                target,
+                self.contexts()
+            )),
+        }
+    }
+
+    fn contexts(&self) -> Vec<&'static str> {
+        vec![TooltipPanel::LABEL]
+    }
+}
+
+impl DefaultTheme for TooltipPanel {
+    fn default_theme() -> Option<Theme<TooltipPanel>> {
+        TooltipPanel::theme().into()
+    }
+}
+
+impl TooltipPanel {
+    pub const LABEL: &'static str = "Label";
+
+    pub fn target(&self) -> Entity {
+        self.target
+    }
+
+    pub fn theme() -> Theme<TooltipPanel> {
+        let base_theme = PseudoTheme::deferred_context(None, TooltipPanel::primary_style);
+        Theme::new(vec![base_theme])
+    }
+
+    fn primary_style(
+        style_builder: &mut StyleBuilder,
+        panel: &TooltipPanel,
+        theme_data: &ThemeData,
+    ) {
+        let theme_spacing = theme_data.spacing;
+        let colors = theme_data.colors();
+        let font = theme_data
+            .text
+            .get(FontStyle::Body, FontScale::Small, FontType::Regular);
+
+        style_builder
+            .position_type(PositionType::Absolute)
+            .max_width(Val::Px(theme_spacing.areas.extra_large))
+            .padding(UiRect::axes(
+                Val::Px(theme_spacing.gaps.medium),
+                Val::Px(theme_spacing.gaps.small),
+            ))
+            .z_index(ZIndex::Global(TOOLTIP_Z_INDEX))
+            .corner_radius(theme_data.shapes.extra_small)
+            .animated()
+            .shape_color(AnimatedVals {
+                idle: colors.surface(Surface::InverseSurface),
+                enter_from: Color::NONE.into(),
+                ..default()
+            })
+            .copy_from(theme_data.enter_animation);
+
+        if panel.label.is_some() {
+            style_builder
+                .switch_target(TooltipPanel::LABEL)
+                .sized_font(font)
+                .animated()
+                .font_color(AnimatedVals {
+                    idle: colors.on(On::InverseSurface),
+                    enter_from: Color::NONE.into(),
+                    ..default()
+                })
+                .copy_from(theme_data.enter_animation);
+        }
+    }
+
+    fn frame() -> impl Bundle {
+        (
+            Name::new("Tooltip"),
+            NodeBundle {
+                focus_policy: FocusPolicy::Pass,
+                // Shown once positioned
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            LockedStyleAttributes::lock(LockableStyleAttribute::FocusPolicy),
+        )
+    }
+}
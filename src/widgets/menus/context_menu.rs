@@ -3,7 +3,7 @@ use bevy::{ecs::system::CommandQueue, prelude::*, ui::FocusPolicy, window::Prima
 use sickle_macros::UiContext;
 use sickle_ui_scaffold::prelude::*;
 
-use super::menu_separators::UiMenuItemSeparatorExt;
+use super::{menu_separators::UiMenuItemSeparatorExt, submenu::Submenu};
 
 const MENU_CONTAINER_Z_INDEX: i32 = 100002;
 
@@ -12,50 +12,80 @@ pub struct ContextMenuPlugin;
 
 impl Plugin for ContextMenuPlugin {
     fn build(&self, app: &mut App) {
-        app.configure_sets(Update, ContextMenuUpdate.after(FluxInteractionUpdate))
-            .add_plugins(ComponentThemePlugin::<ContextMenu>::default())
-            .add_systems(
-                Update,
-                (
-                    update_context_menu_vertical_position,
-                    handle_click_or_touch,
-                    delete_closed_context_menu,
-                    generate_context_menu,
-                    position_added_context_menu,
-                )
-                    .chain()
-                    .in_set(ContextMenuUpdate),
+        app.configure_sets(
+            Update,
+            ContextMenuUpdate
+                .after(FluxInteractionUpdate)
+                .after(LongPressableUpdate),
+        )
+        .add_plugins(ComponentThemePlugin::<ContextMenu>::default())
+        .add_systems(
+            Update,
+            (
+                add_long_press_to_context_menu_generators,
+                update_context_menu_vertical_position,
+                handle_click_or_touch,
+                handle_navigation.after(NavigationUpdate),
+                delete_closed_context_menu,
+                generate_context_menu,
+                position_added_context_menu,
             )
-            .add_systems(PostUpdate, delete_orphaned_context_menus);
+                .chain()
+                .in_set(ContextMenuUpdate),
+        )
+        .add_systems(PostUpdate, delete_orphaned_context_menus);
+    }
+}
+
+fn add_long_press_to_context_menu_generators(
+    q_generators: Query<Entity, (Added<GenerateContextMenu>, Without<LongPressable>)>,
+    mut commands: Commands,
+) {
+    for entity in &q_generators {
+        commands.entity(entity).insert(LongPressable::default());
     }
 }
 
-// TODO: Handle long press on touch
 fn handle_click_or_touch(
     r_mouse: Res<ButtonInput<MouseButton>>,
+    r_touches: Res<Touches>,
     q_context_menu: Query<&Interaction, (With<ContextMenu>, Changed<Interaction>)>,
-    mut q_interacted: Query<(Entity, &Interaction, &mut GenerateContextMenu)>,
+    mut q_interacted: Query<(
+        Entity,
+        &Interaction,
+        Option<&LongPressable>,
+        &mut GenerateContextMenu,
+    )>,
     mut commands: Commands,
 ) {
     let mut close_all = false;
 
-    if r_mouse.just_pressed(MouseButton::Right) {
-        let mut open: Option<Entity> = None;
-        for (entity, interaction, _) in &q_interacted {
-            if *interaction == Interaction::Hovered {
-                open = entity.into();
-                break;
+    let long_pressed = q_interacted
+        .iter()
+        .find(|(_, _, long_press, _)| long_press.is_some_and(|lp| lp.long_pressed()))
+        .map(|(entity, _, long_press, _)| (entity, long_press.and_then(|lp| lp.position)));
+
+    if r_mouse.just_pressed(MouseButton::Right) || long_pressed.is_some() {
+        let mut open: Option<(Entity, Option<Vec2>)> = long_pressed;
+        if open.is_none() {
+            for (entity, interaction, _, _) in &q_interacted {
+                if *interaction == Interaction::Hovered {
+                    open = (entity, None).into();
+                    break;
+                }
             }
         }
 
-        if let Some(open) = open {
-            for (entity, _, mut gen_menu) in &mut q_interacted {
+        if let Some((open, position)) = open {
+            for (entity, _, _, mut gen_menu) in &mut q_interacted {
                 if entity == open {
                     if !gen_menu.is_open {
                         gen_menu.is_open = true;
+                        gen_menu.position = position;
                     } else if let Some(container) = gen_menu.container {
                         commands.entity(container).despawn_recursive();
                         gen_menu.container = None;
+                        gen_menu.position = position;
                     }
                 } else if gen_menu.is_open {
                     gen_menu.is_open = false;
@@ -64,7 +94,9 @@ fn handle_click_or_touch(
         } else {
             close_all = true;
         }
-    } else if r_mouse.any_just_pressed([MouseButton::Left, MouseButton::Middle]) {
+    } else if r_mouse.any_just_pressed([MouseButton::Left, MouseButton::Middle])
+        || r_touches.any_just_pressed()
+    {
         let mut on_context_menu = false;
         for interaction in &q_context_menu {
             if *interaction == Interaction::Pressed {
@@ -80,7 +112,7 @@ fn handle_click_or_touch(
     }
 
     if close_all {
-        for (_, _, mut gen_menu) in &mut q_interacted {
+        for (_, _, _, mut gen_menu) in &mut q_interacted {
             if gen_menu.is_open {
                 gen_menu.is_open = false;
             }
@@ -88,6 +120,44 @@ fn handle_click_or_touch(
     }
 }
 
+fn handle_navigation(
+    mut r_focus: ResMut<FocusManager>,
+    mut events: EventReader<NavigationEvent>,
+    q_context_menus: Query<&ContextMenu>,
+    q_parents: Query<&Parent>,
+    q_focusables: Query<(), With<Focusable>>,
+    q_submenus: Query<(), With<Submenu>>,
+    mut q_gen_menus: Query<&mut GenerateContextMenu>,
+) {
+    for event in events.read() {
+        let Some(target) = event.target else {
+            continue;
+        };
+
+        let context_menu = match event.action {
+            NavigationAction::Back => q_context_menus.get(target).ok(),
+            // Items close the menu on release, same as a mouse click
+            NavigationAction::ConfirmReleased if !q_submenus.contains(target) => q_parents
+                .iter_ancestors(target)
+                .find_map(|entity| q_context_menus.get(entity).ok()),
+            _ => None,
+        };
+        let Some(context_menu) = context_menu else {
+            continue;
+        };
+
+        if let Ok(mut gen_menu) = q_gen_menus.get_mut(context_menu.context) {
+            gen_menu.is_open = false;
+        }
+
+        if q_focusables.contains(context_menu.context) {
+            r_focus.focus(context_menu.context);
+        } else {
+            r_focus.clear();
+        }
+    }
+}
+
 fn delete_closed_context_menu(
     mut q_gen_menus: Query<&mut GenerateContextMenu, Changed<GenerateContextMenu>>,
     mut commands: Commands,
@@ -228,27 +298,28 @@ fn generate_context_menu(world: &mut World) {
     }
 }
 
-// TODO: Handle touch position
 fn position_added_context_menu(
-    q_context_menus: Query<Entity, Added<ContextMenu>>,
+    q_context_menus: Query<(Entity, &ContextMenu), Added<ContextMenu>>,
+    q_gen_menus: Query<&GenerateContextMenu>,
     q_window: Query<&Window, With<PrimaryWindow>>,
-    //r_touches: Res<Touches>,
     mut commands: Commands,
 ) {
     let Ok(window) = q_window.get_single() else {
         return;
     };
 
-    let position = window.cursor_position();
-    // if position.is_none() {
-    //     position = r_touches.first_pressed_position();
-    // }
+    for (entity, context_menu) in &q_context_menus {
+        // Long pressed menus open at the touch point
+        let position = q_gen_menus
+            .get(context_menu.context)
+            .ok()
+            .and_then(|gen_menu| gen_menu.position)
+            .or(window.cursor_position());
 
-    let Some(position) = position else {
-        return;
-    };
+        let Some(position) = position else {
+            continue;
+        };
 
-    for entity in &q_context_menus {
         commands
             .style(entity)
             .position_type(PositionType::Absolute)
@@ -309,6 +380,7 @@ pub trait ContextMenuGenerator {
 pub struct GenerateContextMenu {
     is_open: bool,
     container: Option<Entity>,
+    position: Option<Vec2>,
 }
 
 impl GenerateContextMenu {
@@ -352,6 +424,7 @@ impl ContextMenu {
     fn container(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
         let theme_spacing = theme_data.spacing;
         let colors = theme_data.colors();
+        let elevation = theme_data.elevation.level(Elevation::Level2);
 
         style_builder
             .max_height(Val::Percent(100.))
@@ -360,8 +433,13 @@ impl ContextMenu {
             .padding(UiRect::all(Val::Px(theme_spacing.gaps.small)))
             .flex_direction(FlexDirection::Column)
             .z_index(ZIndex::Global(MENU_CONTAINER_Z_INDEX))
-            .background_color(colors.container(Container::SurfaceMid))
-            .border_color(colors.accent(Accent::Shadow))
+            .corner_radius(theme_data.shapes.extra_small)
+            .shape_color(colors.container(Container::SurfaceMid))
+            .shape_border_width(theme_spacing.borders.extra_small)
+            .shape_border_color(colors.accent(Accent::Shadow))
+            .shadow_color(elevation.shadow_color(colors.accent(Accent::Shadow)))
+            .shadow_offset(elevation.offset)
+            .shadow_blur(elevation.blur)
             .visibility(Visibility::Hidden);
     }
 
@@ -381,6 +459,7 @@ impl ContextMenu {
                 LockableStyleAttribute::Overflow,
             ]),
             Interaction::default(),
+            BackNavigationHandler,
         )
     }
 }
@@ -27,6 +27,7 @@ impl Plugin for MenuPlugin {
                 (
                     handle_click_or_touch,
                     handle_item_interaction,
+                    handle_navigation.after(FocusableUpdate),
                     update_menu_container_visibility,
                 )
                     .chain()
@@ -138,17 +139,62 @@ fn handle_item_interaction(
     }
 }
 
+fn handle_navigation(
+    mut r_focus: ResMut<FocusManager>,
+    mut events: EventReader<NavigationEvent>,
+    q_parents: Query<&Parent>,
+    mut q_menus: Query<(Entity, &mut Menu)>,
+) {
+    for event in events.read() {
+        if event.action != NavigationAction::Back {
+            continue;
+        }
+        let Some(target) = event.target else {
+            continue;
+        };
+
+        if let Ok((_, mut menu)) = q_menus.get_mut(target) {
+            menu.is_open = false;
+        }
+    }
+
+    let focus_changed = r_focus.is_changed();
+    for (entity, mut menu) in &mut q_menus {
+        if !menu.is_changed() && !focus_changed {
+            continue;
+        }
+
+        let focused_within = r_focus.focused().is_some_and(|focused| {
+            focused == entity || q_parents.iter_ancestors(focused).any(|e| e == entity)
+        });
+
+        if menu.is_open && focus_changed && !focused_within {
+            // Focus moved away, e.g. with Tab
+            menu.is_open = false;
+        }
+
+        if !menu.is_open && focused_within && !r_focus.is_focused(entity) {
+            let origin = r_focus.origin();
+            r_focus.focus_with(entity, origin);
+        }
+    }
+}
+
 fn update_menu_container_visibility(
     q_menus: Query<(Entity, &Menu), Changed<Menu>>,
     mut commands: Commands,
 ) {
     for (entity, menu) in &q_menus {
         if menu.is_open {
-            commands.entity(entity).add_pseudo_state(PseudoState::Open);
+            commands
+                .entity(entity)
+                .add_pseudo_state(PseudoState::Open)
+                .insert(BackNavigationHandler);
         } else {
             commands
                 .entity(entity)
-                .remove_pseudo_state(PseudoState::Open);
+                .remove_pseudo_state(PseudoState::Open)
+                .remove::<BackNavigationHandler>();
         }
     }
 }
@@ -209,7 +255,9 @@ impl Menu {
     pub fn theme() -> Theme<Menu> {
         let base_theme = PseudoTheme::deferred(None, Menu::primary_style);
         let open_theme = PseudoTheme::deferred(vec![PseudoState::Open], Menu::open_style);
-        Theme::new(vec![base_theme, open_theme])
+        let focused_theme =
+            PseudoTheme::deferred(vec![PseudoState::Focused], ThemeData::inset_focus_ring);
+        Theme::new(vec![base_theme, open_theme, focused_theme])
     }
 
     fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
@@ -219,6 +267,8 @@ impl Menu {
             .text
             .get(FontStyle::Body, FontScale::Medium, FontType::Regular);
 
+        ThemeData::no_focus_ring(style_builder);
+
         style_builder
             .align_items(AlignItems::Center)
             .padding(UiRect::axes(
@@ -279,6 +329,7 @@ impl Menu {
             Name::new(name),
             ButtonBundle::default(),
             TrackedInteraction::default(),
+            Focusable::default(),
         )
     }
 
@@ -305,8 +356,12 @@ impl Menu {
 pub trait UiMenuExt {
     /// A menu in a MenuBar
     ///
+    /// The menu button is focusable, the confirm action opens and closes the menu. The back
+    /// action closes it and returns focus to the button.
+    ///
     /// ### PseudoState usage
     /// - `PseudoState::Open` is used when the menu panel is visible
+    /// - `PseudoState::Focused` is used when the menu button has keyboard focus
     fn menu(
         &mut self,
         config: MenuConfig,
@@ -1,4 +1,4 @@
-use bevy::{prelude::*, ui::FocusPolicy};
+use bevy::{prelude::*, text::BreakLineOn, ui::FocusPolicy};
 
 use sickle_ui_scaffold::prelude::*;
 
@@ -191,7 +191,9 @@ impl MenuItem {
 
     pub fn theme() -> Theme<MenuItem> {
         let base_theme = PseudoTheme::deferred_context(None, MenuItem::primary_style);
-        Theme::new(vec![base_theme])
+        let focused_theme =
+            PseudoTheme::deferred(vec![PseudoState::Focused], MenuItem::focused_style);
+        Theme::new(vec![base_theme, focused_theme])
     }
 
     fn primary_style(
@@ -217,12 +219,16 @@ impl MenuItem {
             .text
             .get(FontStyle::Body, FontScale::Medium, FontType::Regular);
 
+        ThemeData::no_focus_ring(style_builder);
+
         style_builder
             .justify_content(JustifyContent::End)
             .align_items(AlignItems::Center)
             .height(Val::Px(theme_spacing.areas.small))
             .padding(UiRect::all(Val::Px(theme_spacing.gaps.extra_small)))
             .margin(UiRect::vertical(Val::Px(theme_spacing.gaps.tiny)))
+            .inherited_sized_font(font)
+            .inherited_font_color(colors.on(On::Surface))
             .animated()
             .background_color(AnimatedVals {
                 idle: colors.container(Container::SurfaceMid),
@@ -244,8 +250,7 @@ impl MenuItem {
         style_builder
             .switch_target(MenuItem::LABEL)
             .margin(UiRect::horizontal(Val::Px(theme_spacing.gaps.small)))
-            .sized_font(font.clone())
-            .font_color(colors.on(On::Surface));
+            .line_break(BreakLineOn::NoWrap);
 
         style_builder
             .switch_target(MenuItem::SHORTCUT_CONTAINER)
@@ -256,7 +261,6 @@ impl MenuItem {
 
         style_builder
             .switch_target(MenuItem::SHORTCUT)
-            .sized_font(font)
             .font_color(colors.on(On::SurfaceVariant));
 
         let trailing_icon = match trailing_icon.is_codepoint() {
@@ -271,6 +275,13 @@ impl MenuItem {
             .icon(trailing_icon);
     }
 
+    pub(crate) fn focused_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let colors = theme_data.colors();
+
+        style_builder.background_color(colors.container(Container::SurfaceHighest));
+        ThemeData::inset_focus_ring(style_builder, theme_data);
+    }
+
     fn button(name: String) -> impl Bundle {
         (
             Name::new(name),
@@ -376,7 +387,9 @@ impl UiMenuItemExt for UiBuilder<'_, Entity> {
     fn menu_item(&mut self, config: MenuItemConfig) -> UiBuilder<Entity> {
         let (id, menu_item) = MenuItem::scaffold(self, config);
 
-        self.commands().ui_builder(id).insert(menu_item);
+        self.commands()
+            .ui_builder(id)
+            .insert((menu_item, Focusable::default()));
         self.commands().ui_builder(id)
     }
 }
@@ -31,6 +31,7 @@ impl Plugin for SubmenuPlugin {
                 unlock_submenu_container_on_menu_interaction,
                 update_submenu_timeout,
                 open_submenu_on_hover,
+                handle_navigation.after(NavigationUpdate),
                 close_submenus_on_menu_change,
                 update_open_submenu_containers,
                 update_submenu_state,
@@ -132,6 +133,71 @@ fn open_submenu_on_hover(
     }
 }
 
+fn handle_navigation(
+    mut r_focus: ResMut<FocusManager>,
+    mut events: EventReader<NavigationEvent>,
+    q_submenus: Query<&Submenu>,
+    q_parents: Query<&Parent>,
+    mut q_containers: Query<(Entity, &mut SubmenuContainer, &mut SubmenuContainerState)>,
+    mut commands: Commands,
+) {
+    for event in events.read() {
+        let Some(target) = event.target else {
+            continue;
+        };
+
+        match event.action {
+            NavigationAction::ConfirmReleased => {
+                let Ok(submenu) = q_submenus.get(target) else {
+                    continue;
+                };
+                let Ok((_, mut container, mut state)) = q_containers.get_mut(submenu.container)
+                else {
+                    continue;
+                };
+
+                if !container.is_open {
+                    container.is_open = true;
+                    state.is_locked = true;
+                    state.timeout = MENU_CONTAINER_FADE_TIMEOUT;
+                }
+            }
+            NavigationAction::Back => {
+                if let Ok((_, mut container, mut state)) = q_containers.get_mut(target) {
+                    container.is_open = false;
+                    state.is_locked = false;
+                }
+            }
+            _ => (),
+        }
+    }
+
+    for (entity, container, _) in &mut q_containers {
+        if !container.is_changed() {
+            continue;
+        }
+
+        if container.is_open {
+            commands.entity(entity).insert(BackNavigationHandler);
+            continue;
+        }
+
+        commands.entity(entity).remove::<BackNavigationHandler>();
+
+        // Return focus to the submenu if it was inside the closed container
+        let Some(focused) = r_focus.focused() else {
+            continue;
+        };
+        if focused != entity && !q_parents.iter_ancestors(focused).any(|e| e == entity) {
+            continue;
+        }
+        if let Ok(submenu) = q_parents.get(entity) {
+            let origin = r_focus.origin();
+            r_focus.focus_with(submenu.get(), origin);
+        }
+    }
+}
+
 fn close_submenus_on_menu_change(
     q_menus: Query<Entity, Changed<Menu>>,
     mut q_submenus: Query<(&mut SubmenuContainer, &mut SubmenuContainerState)>,
@@ -364,8 +430,10 @@ impl Submenu {
     pub fn theme() -> Theme<Submenu> {
         let base_theme = PseudoTheme::deferred_context(None, Submenu::primary_style);
         let open_theme = PseudoTheme::deferred_world(vec![PseudoState::Open], Submenu::open_style);
+        let focused_theme =
+            PseudoTheme::deferred(vec![PseudoState::Focused], MenuItem::focused_style);
 
-        Theme::new(vec![base_theme, open_theme])
+        Theme::new(vec![base_theme, open_theme, focused_theme])
     }
 
     fn primary_style(
@@ -449,8 +517,12 @@ impl UiSubmenuSubExt for UiBuilder<'_, Submenu> {
 pub trait UiSubmenuExt {
     /// A submenu in a menu, context menu, or submenu
     ///
+    /// The submenu is focusable, the confirm action opens its panel. The back action from within
+    /// the panel closes it and returns focus to the submenu.
+    ///
     /// ### PseudoState usage
     /// - `PseudoState::Open` is used when the submenu panel is visible
+    /// - `PseudoState::Focused` is used when the submenu has keyboard focus
     fn submenu(
         &mut self,
         config: SubmenuConfig,
@@ -481,7 +553,9 @@ impl UiSubmenuExt for UiBuilder<'_, Entity> {
         let mut content_builder = self.commands().ui_builder(submenu.clone());
         spawn_items(&mut content_builder);
 
-        self.commands().ui_builder(id).insert(submenu);
+        self.commands()
+            .ui_builder(id)
+            .insert((submenu, Focusable::default()));
         self.commands().ui_builder(id)
     }
 }
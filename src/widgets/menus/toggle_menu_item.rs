@@ -171,7 +171,9 @@ impl ToggleMenuItem {
         let base_theme = PseudoTheme::deferred_context(None, ToggleMenuItem::primary_style);
         let checked_theme =
             PseudoTheme::deferred(vec![PseudoState::Checked], ToggleMenuItem::checked_style);
-        Theme::new(vec![base_theme, checked_theme])
+        let focused_theme =
+            PseudoTheme::deferred(vec![PseudoState::Focused], MenuItem::focused_style);
+        Theme::new(vec![base_theme, checked_theme, focused_theme])
     }
 
     fn primary_style(
@@ -199,8 +201,11 @@ impl ToggleMenuItem {
 pub trait UiToggleMenuItemExt {
     /// A toggle menu item in a menu, context menu, or submenu
     /// 
+    /// The item is focusable, the confirm action toggles it while focused.
+    ///
     /// ### PseudoState usage
     /// - `PseudoState::Checked` is used when the item is checked
+    /// - `PseudoState::Focused` is used when the item has keyboard focus
     fn toggle_menu_item(&mut self, config: ToggleMenuItemConfig) -> UiBuilder<Entity>;
 }
 
@@ -213,7 +218,9 @@ impl UiToggleMenuItemExt for UiBuilder<'_, Entity> {
             ..menu_item.into()
         };
 
-        self.commands().ui_builder(id).insert(toggle_item);
+        self.commands()
+            .ui_builder(id)
+            .insert((toggle_item, Focusable::default()));
         self.commands().ui_builder(id)
     }
 }